@@ -0,0 +1,15 @@
+use advent_of_code_template::registry;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn all_days(c: &mut Criterion) {
+    for year in registry::years() {
+        for entry in registry::days(year) {
+            if entry.implemented {
+                (entry.criterion)(c, entry);
+            }
+        }
+    }
+}
+
+criterion_group!(benches, all_days);
+criterion_main!(benches);
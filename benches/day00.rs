@@ -1,14 +1,24 @@
-use advent_of_code_template::{day00::Day00, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day00::Day00, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 0)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 0")
+}
+
 fn parsing() {
-    Day00::parse_input(include_str!("../inputs/0"));
+    Day00::parse_input(&input());
 }
 
 fn part_one() {
-    Day00::solve_part_one(include_str!("../inputs/0"));
+    Day00::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day00::solve_part_two(include_str!("../inputs/0"));
+    Day00::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day01::Day01, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day01::Day01, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 1)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 1")
+}
+
 fn parsing() {
-    Day01::parse_input(include_str!("../inputs/1"));
+    Day01::parse_input(&input());
 }
 
 fn part_one() {
-    Day01::solve_part_one(include_str!("../inputs/1"));
+    Day01::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day01::solve_part_two(include_str!("../inputs/1"));
+    Day01::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
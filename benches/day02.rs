@@ -1,14 +1,24 @@
-use advent_of_code_template::{day02::Day02, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day02::Day02, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 2)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 2")
+}
+
 fn parsing() {
-    Day02::parse_input(include_str!("../inputs/2"));
+    Day02::parse_input(&input());
 }
 
 fn part_one() {
-    Day02::solve_part_one(include_str!("../inputs/2"));
+    Day02::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day02::solve_part_two(include_str!("../inputs/2"));
+    Day02::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day03::Day03, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day03::Day03, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 3)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 3")
+}
+
 fn parsing() {
-    Day03::parse_input(include_str!("../inputs/3"));
+    Day03::parse_input(&input());
 }
 
 fn part_one() {
-    Day03::solve_part_one(include_str!("../inputs/3"));
+    Day03::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day03::solve_part_two(include_str!("../inputs/3"));
+    Day03::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
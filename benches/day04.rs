@@ -1,14 +1,24 @@
-use advent_of_code_template::{day04::Day04, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day04::Day04, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 4)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 4")
+}
+
 fn parsing() {
-    Day04::parse_input(include_str!("../inputs/4"));
+    Day04::parse_input(&input());
 }
 
 fn part_one() {
-    Day04::solve_part_one(include_str!("../inputs/4"));
+    Day04::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day04::solve_part_two(include_str!("../inputs/4"));
+    Day04::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
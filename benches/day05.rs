@@ -1,14 +1,24 @@
-use advent_of_code_template::{day05::Day05, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day05::Day05, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 5)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 5")
+}
+
 fn parsing() {
-    Day05::parse_input(include_str!("../inputs/5"));
+    Day05::parse_input(&input());
 }
 
 fn part_one() {
-    Day05::solve_part_one(include_str!("../inputs/5"));
+    Day05::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day05::solve_part_two(include_str!("../inputs/5"));
+    Day05::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day06::Day06, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day06::Day06, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 6)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 6")
+}
+
 fn parsing() {
-    Day06::parse_input(include_str!("../inputs/6"));
+    Day06::parse_input(&input());
 }
 
 fn part_one() {
-    Day06::solve_part_one(include_str!("../inputs/6"));
+    Day06::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day06::solve_part_two(include_str!("../inputs/6"));
+    Day06::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day07::Day07, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day07::Day07, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 7)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 7")
+}
+
 fn parsing() {
-    Day07::parse_input(include_str!("../inputs/7"));
+    Day07::parse_input(&input());
 }
 
 fn part_one() {
-    Day07::solve_part_one(include_str!("../inputs/7"));
+    Day07::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day07::solve_part_two(include_str!("../inputs/7"));
+    Day07::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
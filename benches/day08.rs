@@ -1,14 +1,24 @@
-use advent_of_code_template::{day08::Day08, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day08::Day08, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 8)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 8")
+}
+
 fn parsing() {
-    Day08::parse_input(include_str!("../inputs/8"));
+    Day08::parse_input(&input());
 }
 
 fn part_one() {
-    Day08::solve_part_one(include_str!("../inputs/8"));
+    Day08::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day08::solve_part_two(include_str!("../inputs/8"));
+    Day08::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day09::Day09, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day09::Day09, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 9)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 9")
+}
+
 fn parsing() {
-    Day09::parse_input(include_str!("../inputs/9"));
+    Day09::parse_input(&input());
 }
 
 fn part_one() {
-    Day09::solve_part_one(include_str!("../inputs/9"));
+    Day09::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day09::solve_part_two(include_str!("../inputs/9"));
+    Day09::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
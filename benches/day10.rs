@@ -1,14 +1,24 @@
-use advent_of_code_template::{day10::Day10, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day10::Day10, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 10)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 10")
+}
+
 fn parsing() {
-    Day10::parse_input(include_str!("../inputs/10"));
+    Day10::parse_input(&input());
 }
 
 fn part_one() {
-    Day10::solve_part_one(include_str!("../inputs/10"));
+    Day10::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day10::solve_part_two(include_str!("../inputs/10"));
+    Day10::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
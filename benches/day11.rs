@@ -1,14 +1,24 @@
-use advent_of_code_template::{day11::Day11, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day11::Day11, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 11)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 11")
+}
+
 fn parsing() {
-    Day11::parse_input(include_str!("../inputs/11"));
+    Day11::parse_input(&input());
 }
 
 fn part_one() {
-    Day11::solve_part_one(include_str!("../inputs/11"));
+    Day11::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day11::solve_part_two(include_str!("../inputs/11"));
+    Day11::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
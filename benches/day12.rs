@@ -1,14 +1,24 @@
-use advent_of_code_template::{day12::Day12, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day12::Day12, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 12)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 12")
+}
+
 fn parsing() {
-    Day12::parse_input(include_str!("../inputs/12"));
+    Day12::parse_input(&input());
 }
 
 fn part_one() {
-    Day12::solve_part_one(include_str!("../inputs/12"));
+    Day12::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day12::solve_part_two(include_str!("../inputs/12"));
+    Day12::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day13::Day13, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day13::Day13, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 13)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 13")
+}
+
 fn parsing() {
-    Day13::parse_input(include_str!("../inputs/13"));
+    Day13::parse_input(&input());
 }
 
 fn part_one() {
-    Day13::solve_part_one(include_str!("../inputs/13"));
+    Day13::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day13::solve_part_two(include_str!("../inputs/13"));
+    Day13::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
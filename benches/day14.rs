@@ -1,14 +1,24 @@
-use advent_of_code_template::{day14::Day14, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day14::Day14, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 14)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 14")
+}
+
 fn parsing() {
-    Day14::parse_input(include_str!("../inputs/14"));
+    Day14::parse_input(&input());
 }
 
 fn part_one() {
-    Day14::solve_part_one(include_str!("../inputs/14"));
+    Day14::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day14::solve_part_two(include_str!("../inputs/14"));
+    Day14::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
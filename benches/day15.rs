@@ -1,14 +1,24 @@
-use advent_of_code_template::{day15::Day15, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day15::Day15, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 15)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 15")
+}
+
 fn parsing() {
-    Day15::parse_input(include_str!("../inputs/15"));
+    Day15::parse_input(&input());
 }
 
 fn part_one() {
-    Day15::solve_part_one(include_str!("../inputs/15"));
+    Day15::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day15::solve_part_two(include_str!("../inputs/15"));
+    Day15::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day16::Day16, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day16::Day16, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 16)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 16")
+}
+
 fn parsing() {
-    Day16::parse_input(include_str!("../inputs/16"));
+    Day16::parse_input(&input());
 }
 
 fn part_one() {
-    Day16::solve_part_one(include_str!("../inputs/16"));
+    Day16::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day16::solve_part_two(include_str!("../inputs/16"));
+    Day16::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
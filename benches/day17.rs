@@ -1,14 +1,24 @@
-use advent_of_code_template::{day17::Day17, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day17::Day17, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 17)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 17")
+}
+
 fn parsing() {
-    Day17::parse_input(include_str!("../inputs/17"));
+    Day17::parse_input(&input());
 }
 
 fn part_one() {
-    Day17::solve_part_one(include_str!("../inputs/17"));
+    Day17::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day17::solve_part_two(include_str!("../inputs/17"));
+    Day17::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day18::Day18, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day18::Day18, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 18)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 18")
+}
+
 fn parsing() {
-    Day18::parse_input(include_str!("../inputs/18"));
+    Day18::parse_input(&input());
 }
 
 fn part_one() {
-    Day18::solve_part_one(include_str!("../inputs/18"));
+    Day18::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day18::solve_part_two(include_str!("../inputs/18"));
+    Day18::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
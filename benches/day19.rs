@@ -1,14 +1,24 @@
-use advent_of_code_template::{day19::Day19, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day19::Day19, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 19)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 19")
+}
+
 fn parsing() {
-    Day19::parse_input(include_str!("../inputs/19"));
+    Day19::parse_input(&input());
 }
 
 fn part_one() {
-    Day19::solve_part_one(include_str!("../inputs/19"));
+    Day19::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day19::solve_part_two(include_str!("../inputs/19"));
+    Day19::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
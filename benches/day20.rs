@@ -1,14 +1,24 @@
-use advent_of_code_template::{day20::Day20, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day20::Day20, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 20)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 20")
+}
+
 fn parsing() {
-    Day20::parse_input(include_str!("../inputs/20"));
+    Day20::parse_input(&input());
 }
 
 fn part_one() {
-    Day20::solve_part_one(include_str!("../inputs/20"));
+    Day20::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day20::solve_part_two(include_str!("../inputs/20"));
+    Day20::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day21::Day21, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day21::Day21, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 21)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 21")
+}
+
 fn parsing() {
-    Day21::parse_input(include_str!("../inputs/21"));
+    Day21::parse_input(&input());
 }
 
 fn part_one() {
-    Day21::solve_part_one(include_str!("../inputs/21"));
+    Day21::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day21::solve_part_two(include_str!("../inputs/21"));
+    Day21::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
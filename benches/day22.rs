@@ -1,14 +1,24 @@
-use advent_of_code_template::{day22::Day22, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day22::Day22, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 22)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 22")
+}
+
 fn parsing() {
-    Day22::parse_input(include_str!("../inputs/22"));
+    Day22::parse_input(&input());
 }
 
 fn part_one() {
-    Day22::solve_part_one(include_str!("../inputs/22"));
+    Day22::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day22::solve_part_two(include_str!("../inputs/22"));
+    Day22::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
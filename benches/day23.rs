@@ -1,14 +1,24 @@
-use advent_of_code_template::{day23::Day23, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day23::Day23, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 23)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 23")
+}
+
 fn parsing() {
-    Day23::parse_input(include_str!("../inputs/23"));
+    Day23::parse_input(&input());
 }
 
 fn part_one() {
-    Day23::solve_part_one(include_str!("../inputs/23"));
+    Day23::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day23::solve_part_two(include_str!("../inputs/23"));
+    Day23::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day24::Day24, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day24::Day24, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 24)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 24")
+}
+
 fn parsing() {
-    Day24::parse_input(include_str!("../inputs/24"));
+    Day24::parse_input(&input());
 }
 
 fn part_one() {
-    Day24::solve_part_one(include_str!("../inputs/24"));
+    Day24::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day24::solve_part_two(include_str!("../inputs/24"));
+    Day24::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -1,14 +1,24 @@
-use advent_of_code_template::{day25::Day25, Solution};
+use advent_of_code_template::{
+    input::InputSource, registry, year2022::day25::Day25, Solution, DEFAULT_YEAR,
+};
+
+fn input() -> String {
+    registry::find(DEFAULT_YEAR, 25)
+        .unwrap()
+        .load_input(&InputSource::default())
+        .expect("Couldn't load input for day 25")
+}
+
 fn parsing() {
-    Day25::parse_input(include_str!("../inputs/25"));
+    Day25::parse_input(&input());
 }
 
 fn part_one() {
-    Day25::solve_part_one(include_str!("../inputs/25"));
+    Day25::solve_part_one(&input());
 }
 
 fn part_two() {
-    Day25::solve_part_two(include_str!("../inputs/25"));
+    Day25::solve_part_two(&input());
 }
 
 iai::main!(parsing, part_one, part_two,);
@@ -0,0 +1,122 @@
+//! Generates a test for every part of every example in `examples/` that has an expected answer,
+//! for `tests/examples.rs` to include. See `src/examples.rs` for the layout.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Must match `DEFAULT_YEAR` in `src/lib.rs`, which the generated tests check.
+const DEFAULT_YEAR: i32 = 2022;
+
+fn main() {
+    let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+    let mut tests = format!(
+        "#[test]\nfn build_default_year_matches() {{\n    assert_eq!({}, advent_of_code_template::DEFAULT_YEAR);\n}}\n",
+        DEFAULT_YEAR
+    );
+    // Each year's tests go in a `yearYYYY` module, like the solutions, so that a filter such as
+    // `year2022::day04_` can't also pick up another year's day 4.
+    let mut years = BTreeMap::<i32, String>::new();
+    for (year, day, dir) in example_dirs(&examples_dir) {
+        let tests = years.entry(year).or_default();
+        let cases = sorted_entries(&dir)
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"));
+        for input_path in cases {
+            let case = input_path.file_stem().unwrap().to_string_lossy();
+            let answers_path = input_path.with_extension("toml");
+            let answers = fs::read_to_string(&answers_path)
+                .unwrap_or_else(|err| panic!("{}: {}", answers_path.display(), err))
+                .parse::<toml::Table>()
+                .unwrap_or_else(|err| panic!("{}: {}", answers_path.display(), err));
+            for (part, key, solve) in [
+                (1, "part1", "solve_part_one"),
+                (2, "part2", "solve_part_two"),
+            ] {
+                if !answers.contains_key(key) {
+                    continue;
+                }
+                writeln!(
+                    tests,
+                    "
+#[test]
+fn day{day:02}_{case}_part{part}() {{
+    let example = advent_of_code_template::examples::Example::load(std::path::Path::new({path:?})).unwrap();
+    example.check({part}, &<advent_of_code_template::year{year}::day{day:02}::Day{day:02} as advent_of_code_template::Solution>::{solve}(&example.input));
+}}",
+                    case = test_name(&case),
+                    part = part,
+                    path = input_path,
+                    year = year,
+                    day = day,
+                    solve = solve,
+                )
+                .unwrap();
+            }
+        }
+    }
+    for (year, year_tests) in years {
+        writeln!(tests, "\nmod year{} {{{}}}", year, year_tests).unwrap();
+    }
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(out_path, tests).unwrap();
+}
+
+/// Every `examples/<day>` directory of the default year and `examples/<year>/<day>` directory of
+/// other years, as `(year, day, dir)`.
+fn example_dirs(examples_dir: &Path) -> Vec<(i32, i32, PathBuf)> {
+    let mut dirs = vec![];
+    for path in sorted_entries(examples_dir) {
+        let Some(number) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<i32>().ok())
+        else {
+            continue;
+        };
+        if (1..=25).contains(&number) {
+            dirs.push((DEFAULT_YEAR, number, path));
+        } else {
+            for day_path in sorted_entries(&path) {
+                if let Some(day) = day_path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| name.parse::<i32>().ok())
+                {
+                    dirs.push((number, day, day_path));
+                }
+            }
+        }
+    }
+    dirs
+}
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    let mut paths = entries
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+/// Turn a case's file name into part of a test function name.
+fn test_name(case: &str) -> String {
+    case.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,192 @@
+//! Heap allocation accounting for each stage of a solution, using a counting global allocator.
+//! Only enabled with the `alloc-stats` feature, as counting slows every allocation down a little.
+
+/// Heap usage of one stage of a solution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes requested by those allocations.
+    pub bytes: u64,
+    /// Most bytes live at once during the stage, beyond what was live when it started.
+    pub peak_bytes: u64,
+}
+
+/// Heap usage of each stage of one run of a solution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StageAllocations {
+    pub parse: AllocStats,
+    pub part_one: AllocStats,
+    pub part_two: AllocStats,
+}
+
+/// Run `f`, returning its heap usage if allocations are being counted. Only allocations made by
+/// the current thread are counted, so days run in parallel don't affect each other's numbers.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (result, stats) = counting::measure(f);
+        (result, Some(stats))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+impl std::fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} bytes, peak {} bytes",
+            self.allocations, self.bytes, self.peak_bytes
+        )
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use super::AllocStats;
+
+    #[derive(Clone, Copy)]
+    struct Counters {
+        allocations: u64,
+        bytes: u64,
+        live_bytes: u64,
+        peak_live_bytes: u64,
+    }
+
+    thread_local! {
+        // Const-initialised, so using it never allocates.
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                allocations: 0,
+                bytes: 0,
+                live_bytes: 0,
+                peak_live_bytes: 0,
+            })
+        };
+    }
+
+    /// Update the current thread's counters. Allocations made while the thread is being torn down,
+    /// after its counters are gone, aren't counted.
+    fn update(f: impl FnOnce(&mut Counters)) {
+        let _ = COUNTERS.try_with(|cell| {
+            let mut counters = cell.get();
+            f(&mut counters);
+            counters.peak_live_bytes = counters.peak_live_bytes.max(counters.live_bytes);
+            cell.set(counters);
+        });
+    }
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                update(|counters| {
+                    counters.allocations += 1;
+                    counters.bytes += layout.size() as u64;
+                    counters.live_bytes += layout.size() as u64;
+                });
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                update(|counters| {
+                    counters.allocations += 1;
+                    counters.bytes += layout.size() as u64;
+                    counters.live_bytes += layout.size() as u64;
+                });
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            update(|counters| {
+                // Memory allocated on another thread can be freed here, so don't underflow.
+                counters.live_bytes = counters.live_bytes.saturating_sub(layout.size() as u64);
+            });
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                update(|counters| {
+                    counters.allocations += 1;
+                    counters.bytes += new_size as u64;
+                    counters.live_bytes = (counters.live_bytes + new_size as u64)
+                        .saturating_sub(layout.size() as u64);
+                });
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+        let before = COUNTERS.with(|cell| {
+            let mut counters = cell.get();
+            counters.peak_live_bytes = counters.live_bytes;
+            cell.set(counters);
+            counters
+        });
+        let result = f();
+        let after = COUNTERS.with(Cell::get);
+        let stats = AllocStats {
+            allocations: after.allocations - before.allocations,
+            bytes: after.bytes - before.bytes,
+            peak_bytes: after.peak_live_bytes - before.live_bytes,
+        };
+        (result, stats)
+    }
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_measure_allocations() {
+        let (_, stats) = measure(|| {
+            let mut total = 0;
+            for _ in 0..3 {
+                let buffer = Vec::<u8>::with_capacity(1000);
+                total += buffer.capacity();
+            }
+            total
+        });
+        assert_eq!(
+            stats,
+            Some(AllocStats {
+                allocations: 3,
+                bytes: 3000,
+                peak_bytes: 1000,
+            })
+        );
+    }
+
+    #[test]
+    fn check_measure_excludes_earlier_allocations() {
+        let earlier = vec![0u8; 5000];
+        let (kept, stats) = measure(|| vec![0u8; 100]);
+        let stats = stats.unwrap();
+        assert_eq!(
+            (stats.allocations, stats.bytes, stats.peak_bytes),
+            (1, 100, 100)
+        );
+        drop((earlier, kept));
+    }
+}
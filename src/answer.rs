@@ -0,0 +1,113 @@
+use std::fmt;
+
+/// A puzzle answer, as returned by `Solution::part_one` and `Solution::part_two`.
+///
+/// Numbers convert with `.into()`, as do strings: a string with more than one line becomes a
+/// `Grid`, with any blank lines around it dropped.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// A number, wide enough for any of the common integer types.
+    Integer(i128),
+    /// A single line of text, e.g. the labels of the crates on top of each stack.
+    Text(String),
+    /// A picture spread over several lines, e.g. the letters drawn by a CRT, one row per string.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// A `Grid` from its rows.
+    pub fn grid<S: Into<String>>(rows: impl IntoIterator<Item = S>) -> Self {
+        Self::Grid(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// The answer as a number, if it is one.
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            Self::Text(text) => text.parse().ok(),
+            Self::Grid(_) => None,
+        }
+    }
+
+    /// Whether the answer is displayed over more than one line.
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Self::Grid(rows) if rows.len() > 1)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::Text(text) => write!(f, "{}", text),
+            Self::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Self::Integer(value as i128)
+                }
+            }
+
+            impl From<&$int> for Answer {
+                fn from(value: &$int) -> Self {
+                    Self::Integer(*value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        let text = text.trim_matches('\n');
+        if text.contains('\n') {
+            Self::grid(text.lines())
+        } else {
+            Self::Text(text.to_string())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        if text.contains('\n') {
+            Self::from(text.as_str())
+        } else {
+            Self::Text(text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_answer_from() {
+        assert_eq!(Answer::from(24000u32), Answer::Integer(24000));
+        assert_eq!(Answer::from(-3i64).as_integer(), Some(-3));
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+        assert_eq!(Answer::from("12".to_string()).as_integer(), Some(12));
+        assert_eq!(
+            Answer::from("\n#..\n.#.\n".to_string()),
+            Answer::grid(["#..", ".#."])
+        );
+    }
+
+    #[test]
+    fn check_answer_display() {
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+        let grid = Answer::grid(["#..", ".#."]);
+        assert!(grid.is_multiline());
+        assert_eq!(grid.to_string(), "#..\n.#.");
+        assert!(!Answer::from(1).is_multiline());
+    }
+}
@@ -0,0 +1,183 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::Answer;
+
+/// The known answers for one day. Either part may be missing, e.g. while part two is unsolved.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct KnownAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Known answers for a year, read from `answers/<year>.toml` with one table per day:
+///
+/// ```toml
+/// [1]
+/// part1 = "70369"
+/// part2 = "203002"
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    // TOML keys are always strings, so days are looked up by their string form.
+    days: BTreeMap<String, KnownAnswers>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartCheck {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    Missing,
+    /// The part wasn't run, e.g. because of `--part`.
+    Skipped,
+}
+
+impl fmt::Display for PartCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected, actual } if expected.contains('\n') || actual.contains('\n') => {
+                write!(f, "FAIL (expected:\n{}\ngot:\n{})", expected, actual)
+            }
+            Self::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {}, got {})", expected, actual)
+            }
+            Self::Missing => write!(f, "missing"),
+            Self::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+/// Path of the answers file for a year, within the crate's `answers` directory.
+pub fn default_answers_path(year: i32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("answers")
+        .join(format!("{}.toml", year))
+}
+
+impl AnswerStore {
+    /// Load an answers file. A year without an answers file yet has no known answers.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), err),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(io::Error::new(
+                err.kind(),
+                format!("{}: {}", path.display(), err),
+            )),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    pub fn get(&self, day: i32) -> Option<&KnownAnswers> {
+        self.days.get(&day.to_string())
+    }
+
+    /// Compare a day's answers against the known answers, where a part that wasn't run has no
+    /// answer. Surrounding whitespace is ignored, so multi-line answers can be written naturally
+    /// in TOML.
+    pub fn check(&self, day: i32, answers: [Option<&Answer>; 2]) -> [PartCheck; 2] {
+        let known = self.get(day).cloned().unwrap_or_default();
+        [
+            check_part(known.part1.as_deref(), answers[0]),
+            check_part(known.part2.as_deref(), answers[1]),
+        ]
+    }
+}
+
+fn check_part(expected: Option<&str>, actual: Option<&Answer>) -> PartCheck {
+    let Some(actual) = actual else {
+        return PartCheck::Skipped;
+    };
+    let actual = actual.to_string();
+    match expected {
+        None => PartCheck::Missing,
+        Some(expected) if expected.trim() == actual.trim() => PartCheck::Pass,
+        Some(expected) => PartCheck::Fail {
+            expected: expected.trim().to_string(),
+            actual: actual.trim().to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[1]
+part1 = "24000"
+part2 = "45000"
+
+[10]
+part1 = "13140"
+part2 = '''
+##..
+..##
+'''
+"#;
+
+    #[test]
+    fn check_answers_parse() {
+        let store = AnswerStore::parse(ANSWERS).unwrap();
+        assert_eq!(store.get(1).unwrap().part1.as_deref(), Some("24000"));
+        assert_eq!(
+            store.get(10).unwrap().part2.as_deref(),
+            Some("##..\n..##\n")
+        );
+        assert_eq!(store.get(2), None);
+    }
+
+    #[test]
+    fn check_answers_check() {
+        let store = AnswerStore::parse(ANSWERS).unwrap();
+        assert_eq!(
+            store.check(1, [Some(&24000.into()), Some(&1.into())]),
+            [
+                PartCheck::Pass,
+                PartCheck::Fail {
+                    expected: "45000".to_string(),
+                    actual: "1".to_string()
+                }
+            ]
+        );
+        assert_eq!(
+            store.check(
+                10,
+                [Some(&13140.into()), Some(&Answer::grid(["##..", "..##"]))]
+            ),
+            [PartCheck::Pass, PartCheck::Pass]
+        );
+        assert_eq!(
+            store.check(3, [Some(&1.into()), Some(&2.into())]),
+            [PartCheck::Missing, PartCheck::Missing]
+        );
+        assert_eq!(
+            store.check(1, [None, Some(&45000.into())]),
+            [PartCheck::Skipped, PartCheck::Pass]
+        );
+    }
+
+    #[test]
+    fn check_answers_file_is_valid() {
+        let store = AnswerStore::load(&default_answers_path(2022)).unwrap();
+        assert!(store.get(1).is_some());
+    }
+}
@@ -0,0 +1,318 @@
+use std::{
+    fmt, io,
+    process::{Command, ExitStatus, Stdio},
+};
+
+use clap::ValueEnum;
+
+#[cfg(feature = "criterion")]
+use crate::{input::InputSource, registry::DayEntry, Solution};
+use crate::{output::format_table, registry};
+
+/// Which benchmarking tool `bench_day` runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum BenchBackend {
+    /// Instruction counts from iai. Needs valgrind.
+    #[default]
+    Iai,
+    /// Wall-clock statistics from criterion, with confidence intervals.
+    Criterion,
+}
+
+/// iai's measurements for one benchmarked function of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IaiStats {
+    /// The bench function, i.e. `parsing`, `part_one` or `part_two`.
+    pub name: String,
+    pub instructions: u64,
+    pub l1_accesses: u64,
+    pub l2_accesses: u64,
+    pub ram_accesses: u64,
+    pub estimated_cycles: u64,
+}
+
+#[derive(Debug)]
+pub enum BenchError {
+    /// `cargo bench` couldn't be started at all.
+    Spawn(io::Error),
+    /// `cargo bench` failed, e.g. because the bench didn't build.
+    Failed { status: ExitStatus, stderr: String },
+    /// `cargo bench` succeeded but printed no results, e.g. because valgrind isn't installed.
+    NoResults { stdout: String },
+    /// No day is registered with that number.
+    UnknownDay { year: i32, day: i32 },
+}
+
+impl fmt::Display for BenchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spawn(err) => write!(f, "couldn't run cargo bench: {}", err),
+            Self::Failed { status, stderr } => {
+                write!(f, "cargo bench failed ({}):\n{}", status, stderr.trim_end())
+            }
+            Self::NoResults { stdout } => {
+                write!(f, "no bench results in output:\n{}", stdout.trim_end())
+            }
+            Self::UnknownDay { year, day } => {
+                write!(f, "day {} isn't registered for {}", day, year)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BenchError {}
+
+/// Benchmark a day. iai results are parsed and returned so they can be tabulated across days,
+/// while criterion's output is passed straight through to stdout and `None` is returned.
+pub fn bench_day(
+    year: &i32,
+    day: &i32,
+    backend: BenchBackend,
+) -> Result<Option<Vec<IaiStats>>, BenchError> {
+    let entry = registry::find(*year, *day).ok_or(BenchError::UnknownDay {
+        year: *year,
+        day: *day,
+    })?;
+    if !entry.implemented {
+        println!("Day {} is not implemented yet", day);
+        return Ok(None);
+    }
+    println!("Benchmarking day {}...", day);
+    let mut command = Command::new("cargo");
+    match backend {
+        BenchBackend::Iai => {
+            command.args(["bench", "--bench", entry.bench_name().as_str(), "--quiet"]);
+            let output = command.output().map_err(BenchError::Spawn)?;
+            check_status(output.status, &output.stderr)?;
+            let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+            let stats = parse_iai_output(&stdout);
+            if stats.is_empty() {
+                return Err(BenchError::NoResults { stdout });
+            }
+            Ok(Some(stats))
+        }
+        BenchBackend::Criterion => {
+            // The criterion target covers every registered day, so filter it down to this one.
+            command.args([
+                "bench",
+                "--bench",
+                "criterion",
+                "--features",
+                "criterion",
+                "--quiet",
+                "--",
+                format!("^{}/", criterion_group_name(*year, *day)).as_str(),
+            ]);
+            let output = command
+                .stdout(Stdio::inherit())
+                .output()
+                .map_err(BenchError::Spawn)?;
+            check_status(output.status, &output.stderr)?;
+            Ok(None)
+        }
+    }
+}
+
+fn check_status(status: ExitStatus, stderr: &[u8]) -> Result<(), BenchError> {
+    if status.success() {
+        Ok(())
+    } else {
+        Err(BenchError::Failed {
+            status,
+            stderr: String::from_utf8_lossy(stderr).into_owned(),
+        })
+    }
+}
+
+/// Parse the stdout of an iai bench, which looks like:
+///
+/// ```text
+/// parsing
+///   Instructions:               41833 (+0.123456%)
+///   L1 Accesses:                55555 (No change)
+///   L2 Accesses:                   12
+///   RAM Accesses:                  34
+///   Estimated Cycles:           57205
+/// ```
+///
+/// Any comparison with the previous run is ignored, as are functions with missing measurements.
+pub fn parse_iai_output(stdout: &str) -> Vec<IaiStats> {
+    let mut results = vec![];
+    let mut name = None;
+    let mut values = [None; 5];
+    let mut finish = |name: Option<String>, values: [Option<u64>; 5]| {
+        if let (Some(name), [Some(i), Some(l1), Some(l2), Some(ram), Some(cycles)]) = (name, values)
+        {
+            results.push(IaiStats {
+                name,
+                instructions: i,
+                l1_accesses: l1,
+                l2_accesses: l2,
+                ram_accesses: ram,
+                estimated_cycles: cycles,
+            });
+        }
+    };
+    for line in stdout.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            finish(name.take(), std::mem::take(&mut values));
+            name = Some(line.trim().to_string());
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let idx = match key.trim() {
+            "Instructions" => 0,
+            "L1 Accesses" => 1,
+            "L2 Accesses" => 2,
+            "RAM Accesses" => 3,
+            "Estimated Cycles" => 4,
+            _ => continue,
+        };
+        values[idx] = value
+            .split_whitespace()
+            .next()
+            .and_then(|value| value.parse().ok());
+    }
+    finish(name, values);
+    results
+}
+
+/// Format iai results for several days as an aligned table, one row per bench function.
+pub fn format_iai_table(results: &[(i32, Vec<IaiStats>)]) -> String {
+    let header = [
+        "Day",
+        "Bench",
+        "Instructions",
+        "L1 Accesses",
+        "L2 Accesses",
+        "RAM Accesses",
+        "Est. Cycles",
+    ]
+    .map(String::from);
+    let mut rows = vec![header];
+    for (day, stats) in results {
+        for stat in stats {
+            rows.push([
+                day.to_string(),
+                stat.name.clone(),
+                stat.instructions.to_string(),
+                stat.l1_accesses.to_string(),
+                stat.l2_accesses.to_string(),
+                stat.ram_accesses.to_string(),
+                stat.estimated_cycles.to_string(),
+            ]);
+        }
+    }
+    // Left-align the bench name, right-align the numbers.
+    format_table(&rows, 1)
+}
+
+/// Name of the criterion benchmark group for a day, e.g. `2022/day05`.
+pub fn criterion_group_name(year: i32, day: i32) -> String {
+    format!("{}/day{:02}", year, day)
+}
+
+/// Benchmark a day's `parse_input`, `part_one` and `part_two` with criterion.
+/// Unlike the iai benches, the parts are measured on already-parsed input, and loading the input
+/// isn't measured at all.
+#[cfg(feature = "criterion")]
+pub fn criterion_benches<S: Solution>(c: &mut criterion::Criterion, entry: &DayEntry) {
+    use criterion::{black_box, BatchSize};
+
+    let input = match entry.load_input(&InputSource::default()) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Skipping day {}: {}", entry.day, err);
+            return;
+        }
+    };
+    let mut group = c.benchmark_group(criterion_group_name(entry.year, entry.day));
+    group.bench_function("parsing", |b| b.iter(|| S::parse_input(black_box(&input))));
+    group.bench_function("part_one", |b| {
+        b.iter_batched(
+            || S::parse_input(&input),
+            |mut parsed| S::part_one(&mut parsed),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("part_two", |b| {
+        b.iter_batched(
+            || S::parse_input(&input),
+            |mut parsed| S::part_two(&mut parsed),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IAI_OUTPUT: &str = "parsing
+  Instructions:                41833 (+0.123456%)
+  L1 Accesses:                 55555 (No change)
+  L2 Accesses:                    12
+  RAM Accesses:                   34
+  Estimated Cycles:            57205
+
+part_one
+  Instructions:              1234567
+  L1 Accesses:               1500000
+  L2 Accesses:                   100
+  RAM Accesses:                  200
+  Estimated Cycles:          1507500
+
+";
+
+    #[test]
+    fn check_parse_iai_output() {
+        let stats = parse_iai_output(IAI_OUTPUT);
+        assert_eq!(
+            stats,
+            vec![
+                IaiStats {
+                    name: "parsing".to_string(),
+                    instructions: 41833,
+                    l1_accesses: 55555,
+                    l2_accesses: 12,
+                    ram_accesses: 34,
+                    estimated_cycles: 57205,
+                },
+                IaiStats {
+                    name: "part_one".to_string(),
+                    instructions: 1234567,
+                    l1_accesses: 1500000,
+                    l2_accesses: 100,
+                    ram_accesses: 200,
+                    estimated_cycles: 1507500,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn check_parse_iai_without_valgrind() {
+        assert_eq!(
+            parse_iai_output("Failed to launch valgrind. Error: exit status: 1.\n"),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_format_iai_table() {
+        let stats = parse_iai_output(IAI_OUTPUT);
+        assert_eq!(
+            format_iai_table(&[(4, stats[..1].to_vec()), (12, stats[1..].to_vec())]),
+            "Day  Bench     Instructions  L1 Accesses  L2 Accesses  RAM Accesses  Est. Cycles
+  4  parsing          41833        55555           12            34        57205
+ 12  part_one       1234567      1500000          100           200      1507500
+"
+        );
+    }
+}
@@ -0,0 +1,406 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Deserialize;
+
+/// Environment variable holding the Advent of Code session cookie, which overrides the config file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable overriding the server's base URL, e.g. to use a local stand-in.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// The shortest time between two requests to the server, including from separate runs.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+pub enum ClientError {
+    Io(io::Error),
+    /// The config file couldn't be parsed.
+    Config(String),
+    /// No session token was found in the environment or the config file.
+    MissingSession,
+    /// The server responded with an error status.
+    Status {
+        status: u16,
+        message: String,
+    },
+    /// The request couldn't be made, e.g. because the server couldn't be reached.
+    Transport(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{}", err),
+            Self::Config(err) => write!(f, "invalid config: {}", err),
+            Self::MissingSession => write!(
+                f,
+                "no session token, set ${} or `session` in aoc.toml",
+                SESSION_ENV
+            ),
+            Self::Status { status, message } => {
+                write!(f, "server responded with {}: {}", status, message)
+            }
+            Self::Transport(err) => write!(f, "request failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Settings for talking to the Advent of Code server, read from `aoc.toml` at the crate root:
+///
+/// ```toml
+/// session = "53616c746564..."
+/// base_url = "http://localhost:8080"
+/// leaderboard = "123456"
+/// ```
+///
+/// Keep this file out of git, as the session token logs in as you.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    /// The id of a private leaderboard, as in its URL.
+    pub leaderboard: Option<String>,
+}
+
+/// Path of the config file, at the root of this crate.
+pub fn default_config_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.toml")
+}
+
+impl Config {
+    /// Load the config file, then apply any overrides from the environment.
+    /// A missing config file is fine, as everything can come from the environment.
+    pub fn load(path: &Path) -> Result<Self, ClientError> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| ClientError::Config(format!("{}: {}", path.display(), err)))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(err) => return Err(err.into()),
+        };
+        if let Ok(session) = std::env::var(SESSION_ENV) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var(BASE_URL_ENV) {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+}
+
+/// A rate-limited client for the Advent of Code server.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    min_interval: Duration,
+    /// Where the time of the last request is kept, so the limit also applies across runs.
+    last_request_path: Option<PathBuf>,
+    last_request: Option<SystemTime>,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, ClientError> {
+        let session = config
+            .session
+            .as_deref()
+            .map(str::trim)
+            .filter(|session| !session.is_empty())
+            .ok_or(ClientError::MissingSession)?;
+        Ok(Self {
+            base_url: config
+                .base_url
+                .as_deref()
+                .unwrap_or(DEFAULT_BASE_URL)
+                .trim_end_matches('/')
+                .to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build(),
+            min_interval: MIN_REQUEST_INTERVAL,
+            last_request_path: None,
+            last_request: None,
+        })
+    }
+
+    /// Wait at least `interval` between requests.
+    pub fn with_min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self
+    }
+
+    /// Keep the time of the last request in `path`, so that separate runs are rate-limited too.
+    pub fn with_last_request_path(mut self, path: PathBuf) -> Self {
+        self.last_request = read_timestamp(&path);
+        self.last_request_path = Some(path);
+        self
+    }
+
+    /// Sleep until another request is allowed, then note that one is being made.
+    fn wait_for_turn(&mut self) -> Result<(), ClientError> {
+        if let Some(last) = self.last_request {
+            let elapsed = SystemTime::now()
+                .duration_since(last)
+                .unwrap_or(Duration::ZERO);
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        let now = SystemTime::now();
+        self.last_request = Some(now);
+        if let Some(path) = &self.last_request_path {
+            let millis = now
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            fs::write(path, millis.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Send a request with the session cookie (and `form` as its body, if given), returning the
+    /// response body.
+    fn request(
+        &mut self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, ClientError> {
+        self.wait_for_turn()?;
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => Err(ClientError::Status {
+                status,
+                message: response
+                    .into_string()
+                    .unwrap_or_default()
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+            }),
+            Err(err) => Err(ClientError::Transport(err.to_string())),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Download a day's puzzle input.
+    pub fn fetch_input(&mut self, year: i32, day: i32) -> Result<String, ClientError> {
+        let request = self
+            .agent
+            .get(&self.url(&format!("/{}/day/{}/input", year, day)));
+        self.request(request, None)
+    }
+
+    /// Download a private leaderboard's JSON.
+    pub fn fetch_leaderboard(&mut self, year: i32, id: &str) -> Result<String, ClientError> {
+        let request = self
+            .agent
+            .get(&self.url(&format!("/{}/leaderboard/private/view/{}.json", year, id)));
+        self.request(request, None)
+    }
+
+    /// Submit an answer for one part of a day, returning the server's response page.
+    pub fn submit_answer(
+        &mut self,
+        year: i32,
+        day: i32,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let request = self
+            .agent
+            .post(&self.url(&format!("/{}/day/{}/answer", year, day)));
+        let level = part.to_string();
+        self.request(request, Some(&[("level", &level), ("answer", answer)]))
+    }
+}
+
+fn read_timestamp(path: &Path) -> Option<SystemTime> {
+    let millis = fs::read_to_string(path).ok()?.trim().parse().ok()?;
+    Some(UNIX_EPOCH + Duration::from_millis(millis))
+}
+
+/// What `fetch_input_to` did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FetchOutcome {
+    Downloaded,
+    /// The file already had an input in it, so nothing was requested.
+    AlreadyPresent,
+}
+
+/// Download a day's input into `path`, unless the file already has something in it.
+pub fn fetch_input_to(
+    client: &mut Client,
+    year: i32,
+    day: i32,
+    path: &Path,
+) -> Result<FetchOutcome, ClientError> {
+    match fs::read_to_string(path) {
+        Ok(existing) if !existing.trim().is_empty() => return Ok(FetchOutcome::AlreadyPresent),
+        Ok(_) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+    let input = client.fetch_input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, input)?;
+    Ok(FetchOutcome::Downloaded)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::{
+        sync::{Arc, Mutex},
+        time::Instant,
+    };
+
+    /// A request received by the mock server: method, URL, cookie and body.
+    pub(crate) type Received = (String, String, String, String);
+
+    /// Start a stand-in server that answers each request with the next of `responses`,
+    /// returning its base URL and the requests it has received.
+    pub(crate) fn mock_server(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, Arc<Mutex<Vec<Received>>>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let received = Arc::new(Mutex::new(vec![]));
+        let log = received.clone();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let mut request = server.recv().unwrap();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string())
+                    .unwrap_or_default();
+                let mut content = String::new();
+                request.as_reader().read_to_string(&mut content).unwrap();
+                log.lock().unwrap().push((
+                    request.method().to_string(),
+                    request.url().to_string(),
+                    cookie,
+                    content,
+                ));
+                let response = tiny_http::Response::from_string(body).with_status_code(status);
+                request.respond(response).unwrap();
+            }
+        });
+        (base_url, received)
+    }
+
+    pub(crate) fn test_client(base_url: &str) -> Client {
+        let config = Config {
+            session: Some("abc123".to_string()),
+            base_url: Some(base_url.to_string()),
+            leaderboard: None,
+        };
+        Client::new(&config)
+            .unwrap()
+            .with_min_interval(Duration::ZERO)
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc_client_{}_{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn check_fetch_input() {
+        let (base_url, received) = mock_server(vec![
+            (200, "1000\n2000\n"),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (200, include_str!("../tests/fixtures/leaderboard.json")),
+        ]);
+        let mut client = test_client(&base_url);
+        assert_eq!(client.fetch_input(2022, 1).unwrap(), "1000\n2000\n");
+        assert!(matches!(
+            client.fetch_input(2022, 25),
+            Err(ClientError::Status { status: 404, .. })
+        ));
+        assert!(client
+            .fetch_leaderboard(2022, "1001")
+            .unwrap()
+            .contains("\"owner_id\": 1001"));
+        let received = received.lock().unwrap();
+        assert_eq!(received[0].0, "GET");
+        assert_eq!(received[0].1, "/2022/day/1/input");
+        assert_eq!(received[0].2, "session=abc123");
+        assert_eq!(received[2].1, "/2022/leaderboard/private/view/1001.json");
+        assert!(matches!(
+            Client::new(&Config::default()),
+            Err(ClientError::MissingSession)
+        ));
+    }
+
+    #[test]
+    fn check_fetch_never_overwrites() {
+        let (base_url, received) = mock_server(vec![(200, "fresh\n")]);
+        let mut client = test_client(&base_url);
+        let path = temp_path("input");
+        fs::write(&path, "existing\n").unwrap();
+        assert_eq!(
+            fetch_input_to(&mut client, 2022, 3, &path).unwrap(),
+            FetchOutcome::AlreadyPresent
+        );
+        assert!(received.lock().unwrap().is_empty());
+        fs::write(&path, "").unwrap();
+        assert_eq!(
+            fetch_input_to(&mut client, 2022, 3, &path).unwrap(),
+            FetchOutcome::Downloaded
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fresh\n");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn check_requests_are_rate_limited() {
+        let (base_url, _) = mock_server(vec![(200, "a"), (200, "b")]);
+        let last_request_path = temp_path("last_request");
+        let _ = fs::remove_file(&last_request_path);
+        let mut client = test_client(&base_url)
+            .with_min_interval(Duration::from_millis(200))
+            .with_last_request_path(last_request_path.clone());
+        client.fetch_input(2022, 1).unwrap();
+        // A new client, as in a separate run, still waits for the earlier request.
+        let mut client = test_client(&base_url)
+            .with_min_interval(Duration::from_millis(200))
+            .with_last_request_path(last_request_path.clone());
+        let start = Instant::now();
+        client.fetch_input(2022, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(150));
+        fs::remove_file(&last_request_path).unwrap();
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+
+pub type AocResult<T> = Result<T, AocError>;
+
+/// Errors a solution can report instead of panicking.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AocError {
+    /// A line of the puzzle input couldn't be parsed.
+    Parse {
+        /// 1-based line number within the input.
+        line: usize,
+        text: String,
+        reason: String,
+    },
+    /// The input as a whole is invalid, e.g. something it should contain is missing, so there's no
+    /// single line to point at.
+    Input { reason: String },
+    /// The input parsed, but a part couldn't produce an answer from it.
+    Solve { part: u8, reason: String },
+}
+
+impl AocError {
+    /// Build a parse error from a 0-based line index, as produced by `lines().enumerate()`.
+    pub fn parse(line_idx: usize, text: &str, reason: impl fmt::Display) -> Self {
+        Self::Parse {
+            line: line_idx + 1,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn input(reason: impl fmt::Display) -> Self {
+        Self::Input {
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn solve(part: u8, reason: impl fmt::Display) -> Self {
+        Self::Solve {
+            part,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { line, text, reason } => {
+                write!(f, "line {}: {} (in {:?})", line, reason, text)
+            }
+            Self::Input { reason } => write!(f, "input: {}", reason),
+            Self::Solve { part, reason } => write!(f, "part {}: {}", part, reason),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_parse_error_display() {
+        assert_eq!(
+            AocError::parse(2, "move x from 1 to 2", "invalid digit found in string").to_string(),
+            "line 3: invalid digit found in string (in \"move x from 1 to 2\")"
+        )
+    }
+
+    #[test]
+    fn check_input_error_display() {
+        assert_eq!(
+            AocError::input("no start square 'S'").to_string(),
+            "input: no start square 'S'"
+        )
+    }
+
+    #[test]
+    fn check_solve_error_display() {
+        assert_eq!(
+            AocError::solve(1, "no marker found").to_string(),
+            "part 1: no marker found"
+        )
+    }
+}
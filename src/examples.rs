@@ -0,0 +1,92 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{answers::KnownAnswers, Answer};
+
+/// An example from a puzzle page, read from `examples/<day>/<case>.txt` (`examples/<year>/<day>/`
+/// for years other than the default), with its expected answers in a `<case>.toml` sidecar:
+///
+/// ```toml
+/// part1 = "24000"
+/// part2 = "45000"
+/// ```
+///
+/// Either answer can be left out, e.g. for an example that's only given for part two.
+/// `build.rs` turns every part with an answer into a test in `tests/examples.rs`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub expected: KnownAnswers,
+}
+
+/// The crate's `examples` directory.
+pub fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")
+}
+
+impl Example {
+    /// Load an example from its input file, and its answers from the sidecar next to it.
+    pub fn load(input_path: &Path) -> io::Result<Self> {
+        let answers_path = input_path.with_extension("toml");
+        let answers = fs::read_to_string(&answers_path).map_err(|err| {
+            io::Error::new(err.kind(), format!("{}: {}", answers_path.display(), err))
+        })?;
+        Ok(Self {
+            input: fs::read_to_string(input_path).map_err(|err| {
+                io::Error::new(err.kind(), format!("{}: {}", input_path.display(), err))
+            })?,
+            expected: toml::from_str(&answers).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", answers_path.display(), err),
+                )
+            })?,
+        })
+    }
+
+    /// Assert that a part's answer matches the expected one, ignoring surrounding whitespace so
+    /// that multi-line answers can be written naturally in TOML.
+    pub fn check(&self, part: u8, actual: &Answer) {
+        let expected = match part {
+            1 => &self.expected.part1,
+            _ => &self.expected.part2,
+        };
+        let expected = expected
+            .as_deref()
+            .unwrap_or_else(|| panic!("No expected answer for part {}", part));
+        assert_eq!(
+            actual.to_string().trim(),
+            expected.trim(),
+            "part {} answer",
+            part
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_load_example() {
+        let example = Example::load(&examples_dir().join("1").join("puzzle.txt")).unwrap();
+        assert!(example.input.starts_with("1000\n2000\n"));
+        assert_eq!(
+            example.expected,
+            KnownAnswers {
+                part1: Some("24000".to_string()),
+                part2: Some("45000".to_string()),
+            }
+        );
+        example.check(1, &24000.into());
+    }
+
+    #[test]
+    #[should_panic(expected = "part 2 answer")]
+    fn check_example_mismatch() {
+        let example = Example::load(&examples_dir().join("1").join("puzzle.txt")).unwrap();
+        example.check(2, &0.into());
+    }
+}
@@ -0,0 +1,350 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::error::{AocError, AocResult};
+
+/// A position in a grid as `(x, y)`, i.e. `(column, row)` with `(0, 0)` at the top left.
+pub type Point = (usize, usize);
+
+/// A direction to move in a grid. Up is towards row 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    /// The four directions that share an edge.
+    pub const ORTHOGONAL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+    /// All eight directions, including diagonals.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// The change in `(x, y)` from one step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+            Self::UpLeft => (-1, -1),
+            Self::UpRight => (1, -1),
+            Self::DownLeft => (-1, 1),
+            Self::DownRight => (1, 1),
+        }
+    }
+}
+
+/// A rectangular grid of cells, such as a character map from a puzzle input, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parse a grid with one row per line, mapping each character to a cell.
+    /// Panics if the lines aren't all the same length; see `try_parse`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        Self::try_parse(input, |c| Ok::<_, String>(cell(c))).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Parse a grid with one row per line, mapping each character to a cell, and reporting the
+    /// line of a character the mapper rejects or of a row with the wrong length.
+    pub fn try_parse<E: fmt::Display>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> AocResult<Self> {
+        let mut width = None;
+        let mut cells = vec![];
+        let mut height = 0;
+        for (idx, line) in input.lines().enumerate() {
+            let row_start = cells.len();
+            for c in line.chars() {
+                cells.push(cell(c).map_err(|err| AocError::parse(idx, line, err))?);
+            }
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(AocError::parse(
+                        idx,
+                        line,
+                        format!("row has {} cells, expected {}", row_width, width),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.1 * self.width + point.0])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.1 * self.width + point.0])
+        } else {
+            None
+        }
+    }
+
+    /// The point one step from `point` in `direction`, if it's within the grid.
+    pub fn step(&self, (x, y): Point, direction: Direction) -> Option<Point> {
+        let (dx, dy) = direction.offset();
+        let point = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(point).then_some(point)
+    }
+
+    /// The points sharing an edge with `point`, within the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The points sharing an edge or a corner with `point`, within the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The points from `point` (not including it) to the edge of the grid in `direction`.
+    pub fn ray(&self, point: Point, direction: Direction) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(self.step(point, direction), move |&point| {
+            self.step(point, direction)
+        })
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` as `chunks` doesn't allow 0, and an empty grid has no cells anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} is outside a grid {} wide",
+            x,
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The point of the first cell (row by row) that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// The point of the first cell (row by row) equal to `value`, e.g. a start marker.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swap rows and columns, so that `(x, y)` moves to `(y, x)`.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Rotate a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(x, y)| (y, self.height - 1 - x))
+    }
+
+    /// Rotate a quarter turn anticlockwise.
+    pub fn rotate_anticlockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(x, y)| (self.width - 1 - y, x))
+    }
+
+    /// A new grid whose cell at each point is this grid's cell at `source(point)`.
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|point| self[source(point)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside a {}x{} grid",
+                point, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", point, width, height))
+    }
+}
+
+/// One line per row, with no separator between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "Sab\ncdE\n";
+
+    #[test]
+    fn check_grid_parse_and_navigate() {
+        let grid = Grid::parse(MAP, |c| c);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.find(&'E'), Some((2, 1)));
+        assert_eq!(grid[(1, 0)], 'a');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        assert_eq!(
+            grid.ray((0, 1), Direction::Right).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1)]
+        );
+        assert_eq!(grid.row(1), &['c', 'd', 'E']);
+        assert_eq!(grid.column(2).collect::<String>(), "bE");
+        assert_eq!(grid.to_string(), "Sab\ncdE");
+        assert_eq!(
+            Grid::try_parse("12\n3", |c| c.to_digit(10).ok_or("not a digit")),
+            Err(AocError::parse(1, "3", "row has 1 cells, expected 2"))
+        );
+        assert_eq!(
+            Grid::try_parse("12\n3x", |c| c.to_digit(10).ok_or("not a digit")),
+            Err(AocError::parse(1, "3x", "not a digit"))
+        );
+    }
+
+    #[test]
+    fn check_grid_transforms() {
+        let grid = Grid::parse(MAP, |c| c);
+        assert_eq!(grid.transpose().to_string(), "Sc\nad\nbE");
+        assert_eq!(grid.rotate_clockwise().to_string(), "cS\nda\nEb");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "bE\nad\nSc");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+        assert_eq!(
+            grid.map(|c| c.is_uppercase())
+                .iter()
+                .filter(|(_, &upper)| upper)
+                .count(),
+            2
+        );
+    }
+}
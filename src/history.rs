@@ -0,0 +1,331 @@
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use clap::ValueEnum;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::SolveResult;
+
+/// A timed stage of a solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parsing"),
+            Self::Part1 => write!(f, "part 1"),
+            Self::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// The cargo profile a run was built with. Debug builds are far slower, so only times from the same
+/// profile are comparable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Profile {
+    Debug,
+    Release,
+}
+
+impl Profile {
+    /// The profile this binary was built with.
+    pub fn current() -> Self {
+        if cfg!(debug_assertions) {
+            Self::Debug
+        } else {
+            Self::Release
+        }
+    }
+}
+
+/// One stage's time from one run, as stored in the history file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimingRecord {
+    pub day: i32,
+    pub stage: Stage,
+    /// `git describe --always --dirty` when the run was recorded.
+    pub commit: String,
+    /// Hash of the input the day was run on, as only times for the same input are comparable.
+    pub input_hash: String,
+    pub profile: Profile,
+    /// The stage's time, or its median with `--repeat`.
+    pub nanos: u64,
+    pub runs: usize,
+    /// Seconds since the Unix epoch.
+    pub recorded_at: u64,
+}
+
+/// What to compare a run's times against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Baseline {
+    /// The most recently recorded time.
+    #[default]
+    Previous,
+    /// The fastest recorded time.
+    Best,
+}
+
+/// A stage's time compared with its baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub stage: Stage,
+    pub current: Duration,
+    pub baseline: Duration,
+    pub baseline_commit: String,
+    /// Relative change, e.g. 0.25 for 25% slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:.2?} vs {:.2?} at {} ({:+.1}%)",
+            self.stage,
+            self.current,
+            self.baseline,
+            self.baseline_commit,
+            self.change * 100.0
+        )?;
+        if self.regressed {
+            write!(f, " REGRESSED")?;
+        }
+        Ok(())
+    }
+}
+
+/// Recorded times for a year, read from `history/<year>.jsonl` with one `TimingRecord` per line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    records: Vec<TimingRecord>,
+}
+
+/// Path of the timing history file for a year, within the crate's `history` directory.
+pub fn default_history_path(year: i32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("history")
+        .join(format!("{}.jsonl", year))
+}
+
+/// Read one JSON value per line of a file, skipping blank lines. A missing file has no values. Errors
+/// name the file, and the line for values that don't parse.
+pub(crate) fn read_jsonl<T: DeserializeOwned>(path: &Path) -> io::Result<Vec<T>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => {
+            return Err(io::Error::new(
+                err.kind(),
+                format!("{}: {}", path.display(), err),
+            ))
+        }
+    };
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            serde_json::from_str(line).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} line {}: {}", path.display(), idx + 1, err),
+                )
+            })
+        })
+        .collect()
+}
+
+impl History {
+    /// Load a history file. A year without one yet has no recorded times.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            records: read_jsonl(path)?,
+        })
+    }
+
+    /// Append records to a history file, creating it if needed.
+    pub fn append(path: &Path, records: &[TimingRecord]) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        for record in records {
+            let line = serde_json::to_string(record).expect("TimingRecord is always serializable");
+            writeln!(file, "{}", line)?;
+        }
+        Ok(())
+    }
+
+    /// Compare a new record against the baseline recorded for the same day, stage, input and
+    /// build profile. `threshold` is the relative slowdown that counts as a regression, e.g. 0.1
+    /// for 10%.
+    pub fn compare(
+        &self,
+        record: &TimingRecord,
+        baseline: Baseline,
+        threshold: f64,
+    ) -> Option<Comparison> {
+        let mut matching = self.records.iter().filter(|old| {
+            old.day == record.day
+                && old.stage == record.stage
+                && old.input_hash == record.input_hash
+                && old.profile == record.profile
+        });
+        let old = match baseline {
+            // Records are appended, so the last one is the latest.
+            Baseline::Previous => matching.next_back(),
+            Baseline::Best => matching.min_by_key(|old| old.nanos),
+        }?;
+        let change = (record.nanos as f64 - old.nanos as f64) / (old.nanos.max(1) as f64);
+        Some(Comparison {
+            stage: record.stage,
+            current: Duration::from_nanos(record.nanos),
+            baseline: Duration::from_nanos(old.nanos),
+            baseline_commit: old.commit.clone(),
+            change,
+            regressed: change > threshold,
+        })
+    }
+}
+
+/// Records for each stage that was run, to be appended to the history.
+pub fn records(
+    day: i32,
+    result: &SolveResult,
+    commit: &str,
+    input_hash: &str,
+) -> Vec<TimingRecord> {
+    let recorded_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs());
+    let runs = result.stats.map_or(1, |stats| stats.runs);
+    [
+        (Stage::Parse, Some(result.parse_time)),
+        (
+            Stage::Part1,
+            result.part_one.as_ref().map(|_| result.part_one_time),
+        ),
+        (
+            Stage::Part2,
+            result.part_two.as_ref().map(|_| result.part_two_time),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(stage, time)| {
+        Some(TimingRecord {
+            day,
+            stage,
+            commit: commit.to_string(),
+            input_hash: input_hash.to_string(),
+            profile: Profile::current(),
+            nanos: time?.as_nanos() as u64,
+            runs,
+            recorded_at,
+        })
+    })
+    .collect()
+}
+
+/// A stable hash of an input (64-bit FNV-1a), so records are only compared for the same input.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// The crate's current git commit, marked `-dirty` if there are uncommitted changes.
+pub fn current_commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(stage: Stage, commit: &str, input_hash: &str, nanos: u64) -> TimingRecord {
+        TimingRecord {
+            day: 5,
+            stage,
+            commit: commit.to_string(),
+            input_hash: input_hash.to_string(),
+            profile: Profile::Release,
+            nanos,
+            runs: 1,
+            recorded_at: 0,
+        }
+    }
+
+    #[test]
+    fn check_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn check_history_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc_history_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(History::load(&path).unwrap(), History::default());
+        let records = vec![
+            record(Stage::Parse, "abc1234", "ff", 100),
+            record(Stage::Part2, "abc1234-dirty", "ff", 200),
+        ];
+        History::append(&path, &records[..1]).unwrap();
+        History::append(&path, &records[1..]).unwrap();
+        assert_eq!(History::load(&path).unwrap(), History { records });
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn check_history_compare() {
+        let history = History {
+            records: vec![
+                record(Stage::Part1, "a", "ff", 1000),
+                record(Stage::Part1, "b", "ff", 1500),
+                record(Stage::Part1, "c", "00", 10),
+                record(Stage::Part2, "c", "ff", 10),
+                TimingRecord {
+                    profile: Profile::Debug,
+                    ..record(Stage::Part1, "e", "ff", 100_000)
+                },
+            ],
+        };
+        let current = record(Stage::Part1, "d", "ff", 1600);
+        let previous = history.compare(&current, Baseline::Previous, 0.1).unwrap();
+        assert_eq!(previous.baseline_commit, "b");
+        assert!(!previous.regressed);
+        let best = history.compare(&current, Baseline::Best, 0.1).unwrap();
+        assert_eq!(best.baseline_commit, "a");
+        assert!(best.regressed);
+        assert_eq!(
+            best.to_string(),
+            "part 1 1.60µs vs 1.00µs at a (+60.0%) REGRESSED"
+        );
+        assert_eq!(
+            history.compare(&record(Stage::Parse, "d", "ff", 1), Baseline::Best, 0.1),
+            None
+        );
+    }
+}
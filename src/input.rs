@@ -0,0 +1,76 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable that overrides the default inputs directory.
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+
+/// Where to read a day's puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// A directory of numbered input files, e.g. `inputs/1` for day 1.
+    Dir(PathBuf),
+    /// A single file, used regardless of which day is being run.
+    File(PathBuf),
+    /// Standard input, read to the end.
+    Stdin,
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        Self::Dir(default_inputs_dir())
+    }
+}
+
+impl InputSource {
+    /// Load a puzzle input from this source. `path` is the file to read, relative to the directory,
+    /// when this is a directory, as given by the day's registry entry.
+    pub fn load(&self, path: &Path) -> io::Result<String> {
+        match self {
+            Self::Dir(dir) => read_file(&dir.join(path)),
+            Self::File(path) => read_file(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// The inputs directory used when none is given on the command line.
+/// This is `$AOC_INPUTS_DIR` if set, otherwise the `inputs` directory at the root of this crate.
+pub fn default_inputs_dir() -> PathBuf {
+    match std::env::var_os(INPUTS_DIR_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"),
+    }
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_load_from_dir() {
+        let input = InputSource::Dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"))
+            .load(Path::new("0"))
+            .unwrap();
+        assert!(input.starts_with("259, 605"))
+    }
+
+    #[test]
+    fn check_load_missing_file() {
+        let err = InputSource::File(PathBuf::from("does/not/exist"))
+            .load(Path::new("1"))
+            .unwrap_err();
+        assert!(err.to_string().contains("does/not/exist"))
+    }
+}
@@ -0,0 +1,191 @@
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::output::format_table;
+
+/// A private leaderboard, in the JSON format served by Advent of Code.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// Missing for members who haven't set a name.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// Each completed day's parts, keyed by day then part number.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub struct Star {
+    /// When the star was earned, in seconds since the Unix epoch.
+    pub get_star_ts: i64,
+}
+
+impl Member {
+    /// The member's name, or how Advent of Code shows anonymous members.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// When the member earned each part's star for a day, if they have.
+    pub fn star_times(&self, day: u32) -> [Option<i64>; 2] {
+        let day = self.completion_day_level.get(&day.to_string());
+        let part = |part: &str| Some(day?.get(part)?.get_star_ts);
+        [part("1"), part("2")]
+    }
+}
+
+/// How to order members in the standings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum SortBy {
+    /// Local score, as on the leaderboard page.
+    #[default]
+    Score,
+    Stars,
+}
+
+/// When a puzzle unlocks: midnight US Eastern time (05:00 UTC) on its day of December.
+pub fn unlock_time(year: i32, day: u32) -> i64 {
+    // Days since the Unix epoch of December 1st, from Howard Hinnant's days_from_civil, where
+    // years start in March so that December 1st is day 275.
+    let era = year.div_euclid(400) as i64;
+    let year_of_era = year as i64 - era * 400;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + 275;
+    let days = era * 146097 + day_of_era - 719468;
+    (days + day as i64 - 1) * 86400 + 5 * 3600
+}
+
+/// Format seconds as `HH:MM:SS`, with hours going past 24 for slow days.
+pub fn format_duration(seconds: i64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Members in standings order, with ties broken by the other measure and then by name.
+    pub fn sorted_members(&self, sort: SortBy) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by_key(|member| {
+            let key = match sort {
+                SortBy::Score => (member.local_score, member.stars),
+                SortBy::Stars => (member.stars, member.local_score),
+            };
+            (Reverse(key), member.display_name())
+        });
+        members
+    }
+
+    /// Days that at least one member has a star for, in order.
+    pub fn days(&self) -> Vec<u32> {
+        let mut days = self
+            .members
+            .values()
+            .flat_map(|member| member.completion_day_level.keys())
+            .filter_map(|day| day.parse().ok())
+            .collect::<Vec<u32>>();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    /// A table of each member's local score and stars.
+    pub fn format_standings(&self, sort: SortBy) -> String {
+        let header = ["Rank", "Name", "Score", "Stars"].map(String::from);
+        let mut rows = vec![header];
+        for (rank, member) in self.sorted_members(sort).iter().enumerate() {
+            rows.push([
+                (rank + 1).to_string(),
+                member.display_name(),
+                member.local_score.to_string(),
+                member.stars.to_string(),
+            ]);
+        }
+        format_table(&rows, 1)
+    }
+
+    /// A day's completion times since the puzzle unlocked, and the time between the two parts,
+    /// for every member with a star that day.
+    pub fn format_day(&self, day: u32, sort: SortBy) -> String {
+        let year = self.event.parse().unwrap_or(crate::DEFAULT_YEAR);
+        let unlock = unlock_time(year, day);
+        let header = ["Name", "Part 1", "Part 2", "Delta"].map(String::from);
+        let mut rows = vec![header];
+        rows.extend(self.sorted_members(sort).into_iter().filter_map(|member| {
+            let [part1, part2] = member.star_times(day);
+            part1?;
+            let time =
+                |ts: Option<i64>| ts.map_or("-".to_string(), |ts| format_duration(ts - unlock));
+            let delta = match (part1, part2) {
+                (Some(part1), Some(part2)) => format_duration(part2 - part1),
+                _ => "-".to_string(),
+            };
+            Some([member.display_name(), time(part1), time(part2), delta])
+        }));
+        format!("Day {}\n{}", day, format_table(&rows, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/leaderboard.json");
+
+    #[test]
+    fn check_unlock_time() {
+        assert_eq!(unlock_time(2022, 1), 1669870800);
+        assert_eq!(unlock_time(2022, 2), 1669957200);
+        assert_eq!(unlock_time(2015, 25), 1451019600);
+    }
+
+    #[test]
+    fn check_format_standings() {
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+        assert_eq!(
+            leaderboard.format_standings(SortBy::Score),
+            "Rank  Name                    Score  Stars
+   1  Ada                        11      3
+   2  (anonymous user #1002)     10      4
+   3  Grace                       0      0
+"
+        );
+        let by_stars = leaderboard.sorted_members(SortBy::Stars);
+        assert_eq!(by_stars[0].id, 1002);
+    }
+
+    #[test]
+    fn check_format_day() {
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+        assert_eq!(leaderboard.days(), vec![1, 2]);
+        assert_eq!(
+            leaderboard.format_day(1, SortBy::Score),
+            "Day 1
+Name                      Part 1    Part 2     Delta
+Ada                     00:05:00  00:10:00  00:05:00
+(anonymous user #1002)  00:03:20  24:03:20  24:00:00
+"
+        );
+        assert!(leaderboard
+            .format_day(2, SortBy::Score)
+            .contains("Ada                     00:46:40         -         -"));
+    }
+}
@@ -1,31 +1,40 @@
-use std::process::Command;
-
-pub mod day00;
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+use std::time::{Duration, Instant};
+
+pub use answer::Answer;
+
+use allocations::StageAllocations;
+use error::AocResult;
+use timing::{Part, RunOptions, RunStats, TimingStats};
+
+#[doc(hidden)]
+pub use inventory;
+
+pub mod allocations;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod error;
+pub mod examples;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod leaderboard;
+pub mod output;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod submit;
+pub mod summary;
+pub mod timing;
+pub mod watch;
+pub mod year2022;
+
+/// The year run when `--year` isn't given. Its inputs live directly in the inputs directory,
+/// while other years use an `inputs/<year>/` subdirectory.
+pub const DEFAULT_YEAR: i32 = 2022;
 
 pub trait Solution {
     type ParsedInput;
@@ -33,87 +42,277 @@ pub trait Solution {
     /// You may wish to parse as you go rather than ahead of your part_one and part_two functions.
     /// If so, just return input_lines in your implementation of parse_input and do the parsing later.
     fn parse_input(input_lines: &str) -> Self::ParsedInput;
-    fn part_one(input: &mut Self::ParsedInput) -> String;
-    fn part_two(input: &mut Self::ParsedInput) -> String;
-    fn solve_part_one(input_lines: &str) -> String {
+    fn part_one(input: &mut Self::ParsedInput) -> Answer;
+    fn part_two(input: &mut Self::ParsedInput) -> Answer;
+    /// Whether `part_two` should get the input `part_one` has already worked on, rather than its own
+    /// freshly parsed copy. Only set this if part two intentionally reuses part one's work.
+    const SHARE_PARSED_INPUT: bool = false;
+    /// Fallible version of `parse_input`, used when running from the command line.
+    /// Override this to report malformed input as an `AocError` rather than panicking.
+    fn try_parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+        Ok(Self::parse_input(input_lines))
+    }
+    fn try_part_one(input: &mut Self::ParsedInput) -> AocResult<Answer> {
+        Ok(Self::part_one(input))
+    }
+    fn try_part_two(input: &mut Self::ParsedInput) -> AocResult<Answer> {
+        Ok(Self::part_two(input))
+    }
+    fn solve_part_one(input_lines: &str) -> Answer {
         Self::part_one(&mut Self::parse_input(input_lines))
     }
-    fn solve_part_two(input_lines: &str) -> String {
+    fn solve_part_two(input_lines: &str) -> Answer {
         Self::part_two(&mut Self::parse_input(input_lines))
     }
     /// Solve the problem and print the solutions to stdout, optionally include wall-clock execution time for this run.
-    fn solve(input_lines: &str, include_time: bool) -> (String, String) {
-        if include_time {
-            Self::solve_with_time(input_lines)
-        } else {
-            let mut input = Self::parse_input(input_lines);
-            let p1 = Self::part_one(&mut input);
-            let p2 = Self::part_two(&mut input);
-            println!("----------");
-            println!("Part 1: {}\nPart 2: {}", p1, p2);
-            (p1, p2)
+    fn solve(input_lines: &str, include_time: bool) -> (Answer, Answer) {
+        Self::try_solve(input_lines, include_time).unwrap_or_else(|err| panic!("{}", err))
+    }
+    fn solve_with_time(input_lines: &str) -> (Answer, Answer) {
+        Self::try_solve_with_time(input_lines).unwrap_or_else(|err| panic!("{}", err))
+    }
+    fn try_solve(input_lines: &str, include_time: bool) -> AocResult<(Answer, Answer)> {
+        let result = Self::try_run(input_lines)?;
+        result.print(include_time);
+        // Both parts are run, so both have answers.
+        Ok((result.part_one.unwrap(), result.part_two.unwrap()))
+    }
+    fn try_solve_with_time(input_lines: &str) -> AocResult<(Answer, Answer)> {
+        Self::try_solve(input_lines, true)
+    }
+    /// Solve the problem without printing anything, timing each stage.
+    fn try_run(input_lines: &str) -> AocResult<SolveResult> {
+        Self::try_run_with(input_lines, &RunOptions::default())
+    }
+    /// Solve the problem (or just `options.part`) `options.warmup + options.repeat` times, each from
+    /// a freshly parsed input, and summarise the timings of the last `options.repeat` runs. The
+    /// answers are from the last run and each stage's time is its median.
+    fn try_run_with(input_lines: &str, options: &RunOptions) -> AocResult<SolveResult> {
+        for _ in 0..options.warmup {
+            Self::try_run_once(input_lines, options.part)?;
         }
+        if options.repeat <= 1 {
+            return Self::try_run_once(input_lines, options.part);
+        }
+        let mut results = vec![];
+        for _ in 0..options.repeat {
+            results.push(Self::try_run_once(input_lines, options.part)?);
+        }
+        let stats = |time: fn(&SolveResult) -> Duration| {
+            TimingStats::from_samples(&results.iter().map(time).collect::<Vec<_>>())
+        };
+        let stats = RunStats {
+            runs: results.len(),
+            parse: stats(|result| result.parse_time),
+            part_one: stats(|result| result.part_one_time),
+            part_two: stats(|result| result.part_two_time),
+        };
+        let last = results.pop().unwrap();
+        Ok(SolveResult {
+            parse_time: stats.parse.median,
+            part_one_time: stats.part_one.median,
+            part_two_time: stats.part_two.median,
+            stats: Some(stats),
+            ..last
+        })
     }
-    fn solve_with_time(input_lines: &str) -> (String, String) {
-        let start_time = std::time::Instant::now();
-        let mut input = Self::parse_input(input_lines);
-        let parse_time = start_time.elapsed().as_micros();
-        let start_time = std::time::Instant::now();
-        let p1 = Self::part_one(&mut input);
-        let p1_time = start_time.elapsed().as_micros();
-        let start_time = std::time::Instant::now();
-        let p2 = Self::part_two(&mut input);
-        let p2_time = start_time.elapsed().as_micros();
-        println!("----------");
-        println!("Parsing... ({} μs)", parse_time);
-        println!("Part 1: {} ({} μs)", p1, p1_time);
-        println!("Part 2: {} ({} μs)", p2, p2_time);
-        (p1, p2)
+    /// Run both parts, or just `part`. Unless `SHARE_PARSED_INPUT` is set, part two gets its own
+    /// freshly parsed input, whose parsing isn't timed. If it is set, running just part two still
+    /// runs part one first, untimed, as part two carries on from where part one left the input.
+    #[doc(hidden)]
+    fn try_run_once(input_lines: &str, part: Option<Part>) -> AocResult<SolveResult> {
+        let (parsed, parse_allocs) = allocations::measure(|| {
+            let start_time = Instant::now();
+            Self::try_parse_input(input_lines).map(|input| (input, start_time.elapsed()))
+        });
+        let (mut input, parse_time) = parsed?;
+        let mut result = SolveResult {
+            part_one: None,
+            part_two: None,
+            parse_time,
+            part_one_time: Duration::ZERO,
+            part_two_time: Duration::ZERO,
+            stats: None,
+            allocations: parse_allocs.map(|parse| StageAllocations {
+                parse,
+                ..Default::default()
+            }),
+        };
+        if part != Some(Part::Two) {
+            let (part_one, allocs) = allocations::measure(|| {
+                let start_time = Instant::now();
+                Self::try_part_one(&mut input).map(|answer| (answer, start_time.elapsed()))
+            });
+            let (part_one, part_one_time) = part_one?;
+            result.part_one = Some(part_one);
+            result.part_one_time = part_one_time;
+            if let (Some(allocations), Some(allocs)) = (&mut result.allocations, allocs) {
+                allocations.part_one = allocs;
+            }
+            if !Self::SHARE_PARSED_INPUT && part.is_none() {
+                input = Self::try_parse_input(input_lines)?;
+            }
+        } else if Self::SHARE_PARSED_INPUT {
+            Self::try_part_one(&mut input)?;
+        }
+        if part != Some(Part::One) {
+            let (part_two, allocs) = allocations::measure(|| {
+                let start_time = Instant::now();
+                Self::try_part_two(&mut input).map(|answer| (answer, start_time.elapsed()))
+            });
+            let (part_two, part_two_time) = part_two?;
+            result.part_two = Some(part_two);
+            result.part_two_time = part_two_time;
+            if let (Some(allocations), Some(allocs)) = (&mut result.allocations, allocs) {
+                allocations.part_two = allocs;
+            }
+        }
+        Ok(result)
     }
 }
 
-pub fn solve_day(day: &i32, include_time: bool) {
-    match day {
-        0 => day00::Day00::solve(include_str!("../inputs/0"), include_time),
-        1 => day01::Day01::solve(include_str!("../inputs/1"), include_time),
-        2 => day02::Day02::solve(include_str!("../inputs/2"), include_time),
-        3 => day03::Day03::solve(include_str!("../inputs/3"), include_time),
-        4 => day04::Day04::solve(include_str!("../inputs/4"), include_time),
-        5 => day05::Day05::solve(include_str!("../inputs/5"), include_time),
-        6 => day06::Day06::solve(include_str!("../inputs/6"), include_time),
-        7 => day07::Day07::solve(include_str!("../inputs/7"), include_time),
-        8 => day08::Day08::solve(include_str!("../inputs/8"), include_time),
-        9 => day09::Day09::solve(include_str!("../inputs/9"), include_time),
-        10 => day10::Day10::solve(include_str!("../inputs/10"), include_time),
-        11 => day11::Day11::solve(include_str!("../inputs/11"), include_time),
-        12 => day12::Day12::solve(include_str!("../inputs/12"), include_time),
-        13 => day13::Day13::solve(include_str!("../inputs/13"), include_time),
-        14 => day14::Day14::solve(include_str!("../inputs/14"), include_time),
-        15 => day15::Day15::solve(include_str!("../inputs/15"), include_time),
-        16 => day16::Day16::solve(include_str!("../inputs/16"), include_time),
-        17 => day17::Day17::solve(include_str!("../inputs/17"), include_time),
-        18 => day18::Day18::solve(include_str!("../inputs/18"), include_time),
-        19 => day19::Day19::solve(include_str!("../inputs/19"), include_time),
-        20 => day20::Day20::solve(include_str!("../inputs/20"), include_time),
-        21 => day21::Day21::solve(include_str!("../inputs/21"), include_time),
-        22 => day22::Day22::solve(include_str!("../inputs/22"), include_time),
-        23 => day23::Day23::solve(include_str!("../inputs/23"), include_time),
-        24 => day24::Day24::solve(include_str!("../inputs/24"), include_time),
-        25 => day25::Day25::solve(include_str!("../inputs/25"), include_time),
-        _ => panic!("Day not found"),
-    };
+/// The answers from running a solution, with the wall-clock time taken by each stage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveResult {
+    /// The answer to part one, unless only part two was run.
+    pub part_one: Option<Answer>,
+    /// The answer to part two, unless only part one was run.
+    pub part_two: Option<Answer>,
+    pub parse_time: Duration,
+    pub part_one_time: Duration,
+    pub part_two_time: Duration,
+    /// Statistics across runs when the solution was run repeatedly, in which case the times above
+    /// are the medians.
+    pub stats: Option<RunStats>,
+    /// Heap usage of each stage (of the last run), if built with the `alloc-stats` feature.
+    pub allocations: Option<StageAllocations>,
 }
 
-pub fn bench_day(day: &i32) {
-    println!("Benchmarking day {}...", day);
-    let result = Command::new("cargo")
-        .args([
-            "bench",
-            "--bench",
-            format!("day{:02}", day).as_str(),
-            "--quiet",
-        ])
-        .output()
-        .expect("Failed to run benchmark");
-    println!("{}", String::from_utf8(result.stdout).unwrap());
+impl SolveResult {
+    /// Total time taken to parse the input and solve the parts that were run.
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.part_one_time + self.part_two_time
+    }
+
+    /// Print the answers to stdout below a separator, optionally including the time taken by each stage.
+    pub fn print(&self, include_time: bool) {
+        println!("----------");
+        self.print_answers(include_time);
+    }
+
+    /// Print the answers to stdout, optionally including the time taken by each stage.
+    pub fn print_answers(&self, include_time: bool) {
+        if include_time {
+            match &self.stats {
+                Some(stats) => println!("Parsing... ({}, {} runs)", stats.parse, stats.runs),
+                None => println!("Parsing... ({} μs)", self.parse_time.as_micros()),
+            }
+        }
+        let parts = [
+            (
+                1,
+                &self.part_one,
+                self.part_one_time,
+                self.stats.map(|s| s.part_one),
+            ),
+            (
+                2,
+                &self.part_two,
+                self.part_two_time,
+                self.stats.map(|s| s.part_two),
+            ),
+        ];
+        for (part, answer, time, stats) in parts {
+            let Some(answer) = answer else {
+                continue;
+            };
+            let suffix = match (include_time, stats) {
+                (true, Some(stats)) => format!(" ({})", stats),
+                (true, None) => format!(" ({} μs)", time.as_micros()),
+                (false, _) => String::new(),
+            };
+            // Put multi-line answers below the label, so their rows line up.
+            if answer.is_multiline() {
+                println!("Part {}:{}\n{}", part, suffix, answer);
+            } else {
+                println!("Part {}: {}{}", part, answer, suffix);
+            }
+        }
+        if let Some(allocations) = &self.allocations {
+            println!("Heap usage:");
+            println!("  Parsing: {}", allocations.parse);
+            if self.part_one.is_some() {
+                println!("  Part 1: {}", allocations.part_one);
+            }
+            if self.part_two.is_some() {
+                println!("  Part 2: {}", allocations.part_two);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Part one consumes its input, so part two only sees it if the input is shared.
+    struct Consuming;
+
+    impl Solution for Consuming {
+        type ParsedInput = Vec<u32>;
+
+        fn parse_input(input_lines: &str) -> Self::ParsedInput {
+            input_lines
+                .lines()
+                .map(|line| line.parse().unwrap())
+                .collect()
+        }
+
+        fn part_one(input: &mut Self::ParsedInput) -> Answer {
+            input.drain(..).sum::<u32>().into()
+        }
+
+        fn part_two(input: &mut Self::ParsedInput) -> Answer {
+            input.len().into()
+        }
+    }
+
+    struct Sharing;
+
+    impl Solution for Sharing {
+        type ParsedInput = Vec<u32>;
+        const SHARE_PARSED_INPUT: bool = true;
+
+        fn parse_input(input_lines: &str) -> Self::ParsedInput {
+            Consuming::parse_input(input_lines)
+        }
+
+        fn part_one(input: &mut Self::ParsedInput) -> Answer {
+            Consuming::part_one(input)
+        }
+
+        fn part_two(input: &mut Self::ParsedInput) -> Answer {
+            Consuming::part_two(input)
+        }
+    }
+
+    #[test]
+    fn check_parts_get_fresh_input() {
+        let result = Consuming::try_run("1\n2\n3").unwrap();
+        assert_eq!(result.part_one, Some(Answer::from(6)));
+        assert_eq!(result.part_two, Some(Answer::from(3)));
+        let result = Sharing::try_run("1\n2\n3").unwrap();
+        assert_eq!(result.part_two, Some(Answer::from(0)));
+    }
+
+    #[test]
+    fn check_run_single_part() {
+        let options = RunOptions {
+            part: Some(Part::Two),
+            ..RunOptions::default()
+        };
+        let result = Sharing::try_run_with("1\n2\n3", &options).unwrap();
+        assert_eq!(result.part_one, None);
+        assert_eq!(result.part_two, Some(Answer::from(0)));
+    }
 }
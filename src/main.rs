@@ -1,42 +1,849 @@
-use advent_of_code_template::{bench_day, solve_day};
-use clap::Parser;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use advent_of_code_template::{
+    answers::{default_answers_path, AnswerStore, PartCheck},
+    bench::{bench_day, format_iai_table, BenchBackend},
+    client::{default_config_path, fetch_input_to, Client, Config, FetchOutcome},
+    error::AocResult,
+    history::{self, default_history_path, Baseline, History, TimingRecord},
+    input::{default_inputs_dir, InputSource},
+    leaderboard::{Leaderboard, SortBy},
+    output::{DayRecord, OutputFormat},
+    registry::{self, DayEntry},
+    report::{self, ReportRow},
+    runner::{catch_panic, max_jobs, run_in_order},
+    scaffold,
+    submit::{default_submissions_path, submit, Submissions, Verdict},
+    summary::{format_summary_table, DayStatus, SummaryRow},
+    timing::{Part, RunOptions},
+    watch, Answer, SolveResult, DEFAULT_YEAR,
+};
+use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(author="Finlay Wojtan", version="0.1.0", about="Advent of Code test and benchmarking template", long_about = None)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Selects a single day to run. If not specified, all days are run.
     day: Option<i32>,
 
-    /// Benchmarks the solution for given days.
-    #[arg(short, long)]
-    bench: bool,
+    /// Selects the Advent of Code year to run.
+    #[arg(short, long, global = true, default_value_t = DEFAULT_YEAR)]
+    year: i32,
+
+    /// Benchmarks the solution for given days, with iai unless criterion is chosen.
+    #[arg(short, long, value_enum, num_args = 0..=1, default_missing_value = "iai")]
+    bench: Option<BenchBackend>,
 
-    /// Whether to include wall-clock execution time in the output.
+    /// Shows wall-clock execution times, and records them in history/<year>.jsonl.
     #[arg(short, long, conflicts_with = "bench")]
     time: bool,
+
+    /// Compares each part's time with the previous (or best) time recorded for the same input.
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "previous", requires = "time", conflicts_with_all = ["bench", "format"])]
+    compare: Option<Baseline>,
+
+    /// How much slower than its recorded time, in percent, a part must be to count as a regression.
+    #[arg(long, default_value_t = 10.0, requires = "compare")]
+    threshold: f64,
+
+    /// Reads the puzzle input from this file instead of the inputs directory.
+    #[arg(short, long, requires = "day", conflicts_with_all = ["stdin", "bench"])]
+    input: Option<PathBuf>,
+
+    /// Reads the puzzle input from stdin instead of the inputs directory.
+    #[arg(long, requires = "day", conflicts_with = "bench")]
+    stdin: bool,
+
+    /// Checks answers against answers/<year>.toml, exiting with an error if any don't match.
+    #[arg(short, long, conflicts_with = "bench")]
+    check: bool,
+
+    /// Output format for answers and timings.
+    #[arg(short, long, value_enum, default_value_t, conflicts_with_all = ["bench", "check"])]
+    format: OutputFormat,
+
+    /// Solves up to this many days at once (0 for one per CPU). Output is still in day order.
+    #[arg(short, long, default_value_t = 1, conflicts_with_all = ["bench", "stdin"])]
+    jobs: usize,
+
+    /// Runs only this part of each day.
+    #[arg(short, long, value_enum, conflicts_with = "bench")]
+    part: Option<Part>,
+
+    /// Times this many runs of each day, each from freshly parsed input, and reports statistics.
+    #[arg(short, long, default_value_t = 1, conflicts_with = "bench")]
+    repeat: usize,
+
+    /// Untimed runs of each day before the timed ones, when using --repeat.
+    #[arg(long, default_value_t = 1, requires = "repeat")]
+    warmup: usize,
+
+    /// Lists the registered days and exits.
+    #[arg(short, long, conflicts_with_all = ["bench", "time"])]
+    list: bool,
+
+    /// Directory containing the numbered input files (defaults to $AOC_INPUTS_DIR, then ./inputs).
+    #[arg(long, conflicts_with = "bench")]
+    inputs_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generates the module, bench and input file for a new day from the template.
+    New {
+        day: i32,
+
+        /// The puzzle's title (defaults to "Day <day>").
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Re-runs a day's tests and solution whenever its module, input or examples change.
+    Watch {
+        day: i32,
+
+        /// Extra arguments for each run of the solution, given after `--` (e.g. `-- --part 2`).
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// Downloads puzzle inputs into the inputs directory, leaving any that are already there.
+    Fetch {
+        #[arg(required = true)]
+        days: Vec<i32>,
+    },
+    /// Solves one part of a day and submits the answer, unless earlier submissions rule it out.
+    Submit {
+        day: i32,
+
+        #[arg(value_enum)]
+        part: Part,
+
+        /// Submits this answer instead of solving the day, e.g. letters read from a picture.
+        #[arg(long)]
+        answer: Option<String>,
+    },
+    /// Shows a private leaderboard's standings and each day's completion times.
+    Leaderboard {
+        /// Reads the leaderboard JSON from this file instead of downloading it.
+        #[arg(long, conflicts_with = "id")]
+        file: Option<PathBuf>,
+
+        /// The leaderboard to download (defaults to `leaderboard` in aoc.toml).
+        #[arg(long)]
+        id: Option<String>,
+
+        /// How to order members.
+        #[arg(long, value_enum, default_value_t)]
+        sort: SortBy,
+
+        /// Only shows completion times for this day.
+        #[arg(long)]
+        day: Option<u32>,
+    },
+    /// Runs every day and writes a results table into the marked section of a Markdown file.
+    Report {
+        /// The file to update, relative to the crate root. It's created if it doesn't exist.
+        #[arg(long, default_value = "README.md")]
+        output: PathBuf,
+
+        /// Also benchmarks each day with iai and includes its instruction counts.
+        #[arg(long)]
+        iai: bool,
+    },
+}
+
+impl Cli {
+    fn input_source(&self) -> InputSource {
+        if let Some(path) = &self.input {
+            InputSource::File(path.clone())
+        } else if self.stdin {
+            InputSource::Stdin
+        } else if let Some(dir) = &self.inputs_dir {
+            InputSource::Dir(dir.clone())
+        } else {
+            InputSource::default()
+        }
+    }
+}
+
+/// The registered entry for a day, or exit with the days that are registered.
+fn find_day(year: i32, day: i32) -> &'static DayEntry {
+    registry::find(year, day).unwrap_or_else(|| {
+        eprintln!(
+            "Day {} isn't registered for {} (registered days: {:?})",
+            day,
+            year,
+            registry::days(year)
+                .iter()
+                .map(|entry| entry.day)
+                .collect::<Vec<_>>()
+        );
+        std::process::exit(1);
+    })
+}
+
+fn list_days(year: i32) {
+    println!("{}:", year);
+    for entry in registry::days(year) {
+        let status = if entry.implemented {
+            ""
+        } else {
+            " (not implemented)"
+        };
+        println!("{:>2}: {}{}", entry.day, entry.title, status);
+    }
+}
+
+/// Tally of `--check` results across all the days run.
+#[derive(Default)]
+struct CheckSummary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+impl CheckSummary {
+    fn record(&mut self, check: &PartCheck) {
+        match check {
+            PartCheck::Pass => self.passed += 1,
+            PartCheck::Fail { .. } => self.failed += 1,
+            PartCheck::Missing => self.missing += 1,
+            PartCheck::Skipped => {}
+        }
+    }
+}
+
+/// What happened when a day was run, before anything is printed.
+enum DayOutcome {
+    Unimplemented,
+    LoadFailed(io::Error),
+    /// The input file is empty, e.g. because it hasn't been downloaded yet.
+    EmptyInput,
+    /// The day ran, on the input with this hash.
+    Ran(AocResult<Box<SolveResult>>, String),
+    /// The day panicked, with the panic's message and location.
+    Panicked(String),
+}
+
+impl DayOutcome {
+    fn status(&self) -> DayStatus {
+        match self {
+            Self::Unimplemented => DayStatus::Template,
+            Self::EmptyInput => DayStatus::EmptyInput,
+            Self::LoadFailed(_) | Self::Ran(Err(_), _) => DayStatus::Failed,
+            Self::Panicked(_) => DayStatus::Panicked,
+            Self::Ran(Ok(_), _) => DayStatus::Solved,
+        }
+    }
+}
+
+fn run_day(entry: &DayEntry, source: &InputSource, options: &RunOptions) -> DayOutcome {
+    if !entry.implemented {
+        return DayOutcome::Unimplemented;
+    }
+    match entry.load_input(source) {
+        Ok(input_lines) if input_lines.trim().is_empty() => DayOutcome::EmptyInput,
+        Ok(input_lines) => match catch_panic(|| (entry.run)(&input_lines, options)) {
+            Ok(result) => DayOutcome::Ran(result.map(Box::new), history::input_hash(&input_lines)),
+            Err(panic) => DayOutcome::Panicked(panic),
+        },
+        Err(err) => DayOutcome::LoadFailed(err),
+    }
+}
+
+/// Prints each day's outcome in the chosen format, and checks its answers if asked to.
+struct Reporter {
+    format: OutputFormat,
+    include_time: bool,
+    part: Option<Part>,
+    answer_store: Option<AnswerStore>,
+    check_summary: CheckSummary,
+    /// Days that didn't produce answers, because they errored or panicked.
+    failed_days: Vec<i32>,
+    /// A row per day run, for the end-of-run summary.
+    summary: Vec<SummaryRow>,
+    /// Where timings are recorded, on `--time` runs.
+    history: Option<TimingHistory>,
+}
+
+/// Records each day's timings, and compares them with earlier ones if asked to.
+struct TimingHistory {
+    path: PathBuf,
+    /// Times recorded before this run.
+    recorded: History,
+    commit: String,
+    compare: Option<Baseline>,
+    /// Relative slowdown that counts as a regression, e.g. 0.1 for 10%.
+    threshold: f64,
+    /// Times from this run, appended to the file once every day has been run.
+    new_records: Vec<TimingRecord>,
+    /// Comparisons for the day just recorded.
+    comparisons: Vec<history::Comparison>,
+    regressions: usize,
+}
+
+impl TimingHistory {
+    fn record(&mut self, day: i32, result: &SolveResult, input_hash: &str) {
+        let records = history::records(day, result, &self.commit, input_hash);
+        self.comparisons = match self.compare {
+            Some(baseline) => records
+                .iter()
+                .filter_map(|record| self.recorded.compare(record, baseline, self.threshold))
+                .collect(),
+            None => vec![],
+        };
+        self.regressions += self
+            .comparisons
+            .iter()
+            .filter(|comparison| comparison.regressed)
+            .count();
+        self.new_records.extend(records);
+    }
+
+    fn print_comparisons(&self) {
+        if self.compare.is_some() && self.comparisons.is_empty() {
+            println!("No recorded times to compare with");
+        }
+        for comparison in &self.comparisons {
+            println!("Compared: {}", comparison);
+        }
+    }
+}
+
+impl Reporter {
+    fn report(&mut self, entry: &DayEntry, outcome: DayOutcome) {
+        if matches!(
+            outcome,
+            DayOutcome::LoadFailed(_) | DayOutcome::Ran(Err(_), _) | DayOutcome::Panicked(_)
+        ) {
+            self.failed_days.push(entry.day);
+        }
+        if let (Some(history), DayOutcome::Ran(Ok(result), input_hash)) =
+            (&mut self.history, &outcome)
+        {
+            history.record(entry.day, result, input_hash);
+        }
+        let mut row = SummaryRow::new(entry.day, outcome.status());
+        if let DayOutcome::Ran(Ok(result), _) = &outcome {
+            row.part_one = result.part_one.clone();
+            row.part_two = result.part_two.clone();
+            row.time = self.include_time.then(|| result.total_time());
+        }
+        self.summary.push(row);
+        if self.format != OutputFormat::Text {
+            let record = match outcome {
+                DayOutcome::Unimplemented | DayOutcome::EmptyInput => return,
+                DayOutcome::LoadFailed(err) => {
+                    DayRecord::failed(entry, format!("Couldn't load input: {}", err))
+                }
+                DayOutcome::Ran(result, _) => DayRecord::new(entry, &result.map(|result| *result)),
+                DayOutcome::Panicked(panic) => DayRecord::failed(entry, panic),
+            };
+            match self.format {
+                OutputFormat::Json => println!("{}", record.to_json()),
+                OutputFormat::Csv => println!("{}", record.to_csv()),
+                OutputFormat::Text => unreachable!(),
+            }
+            return;
+        }
+        let answers = match outcome {
+            DayOutcome::Unimplemented => {
+                println!("----------");
+                println!("Day {} is not implemented yet", entry.day);
+                return;
+            }
+            DayOutcome::EmptyInput => {
+                println!("----------");
+                println!("Day {} has an empty input file", entry.day);
+                return;
+            }
+            DayOutcome::LoadFailed(err) => {
+                println!("----------");
+                println!("Day {} FAILED: couldn't load input: {}", entry.day, err);
+                None
+            }
+            DayOutcome::Ran(Ok(result), _) => {
+                println!("----------");
+                println!("Day {}: {}", entry.day, entry.title);
+                result.print_answers(self.include_time);
+                if let Some(history) = &self.history {
+                    history.print_comparisons();
+                }
+                Some((result.part_one, result.part_two))
+            }
+            DayOutcome::Ran(Err(err), _) => {
+                println!("----------");
+                println!("Day {} FAILED: {}", entry.day, err);
+                None
+            }
+            DayOutcome::Panicked(panic) => {
+                println!("----------");
+                println!("Day {} FAILED: {}", entry.day, panic);
+                None
+            }
+        };
+        if let Some(store) = &self.answer_store {
+            match answers {
+                Some(answers) => {
+                    let [part1, part2] =
+                        store.check(entry.day, [answers.0.as_ref(), answers.1.as_ref()]);
+                    println!(
+                        "Day {} check: part 1 {}, part 2 {}",
+                        entry.day, part1, part2
+                    );
+                    if matches!(part1, PartCheck::Fail { .. })
+                        || matches!(part2, PartCheck::Fail { .. })
+                    {
+                        if let Some(row) = self.summary.last_mut() {
+                            row.status = DayStatus::Mismatched;
+                        }
+                    }
+                    self.check_summary.record(&part1);
+                    self.check_summary.record(&part2);
+                }
+                None => {
+                    println!("Day {} check: FAIL (no answers produced)", entry.day);
+                    self.check_summary.failed += if self.part.is_some() { 1 } else { 2 };
+                }
+            }
+        }
+    }
+}
+
+fn bench_days(days: &[&DayEntry], backend: BenchBackend) {
+    let mut iai_results = vec![];
+    let mut bench_failed = false;
+    for entry in days {
+        match bench_day(&entry.year, &entry.day, backend) {
+            Ok(Some(stats)) => iai_results.push((entry.day, stats)),
+            Ok(None) => {}
+            Err(err) => {
+                eprintln!("Day {} bench failed: {}", entry.day, err);
+                bench_failed = true;
+            }
+        }
+    }
+    if !iai_results.is_empty() {
+        print!("{}", format_iai_table(&iai_results));
+    }
+    if bench_failed {
+        std::process::exit(1);
+    }
+}
+
+fn new_day(year: i32, day: i32, title: Option<String>) {
+    let title = title.unwrap_or_else(|| format!("Day {}", day));
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    match scaffold::new_day(root, year, day, &title) {
+        Ok(written) => {
+            for path in written {
+                println!(
+                    "Wrote {}",
+                    path.strip_prefix(root).unwrap_or(&path).display()
+                );
+            }
+        }
+        Err(err) => {
+            eprintln!("Couldn't create day {}: {}", day, err);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn write_report(year: i32, output: &Path, iai: bool) {
+    let days = registry::days(year)
+        .into_iter()
+        .filter(|entry| entry.day != 0)
+        .collect::<Vec<_>>();
+    let mut rows = vec![];
+    for entry in days {
+        eprintln!("Running day {}...", entry.day);
+        let outcome = run_day(entry, &InputSource::default(), &RunOptions::default());
+        let mut row = ReportRow::new(entry.day, entry.title, outcome.status());
+        if let DayOutcome::Ran(Ok(result), _) = &outcome {
+            row.part_one_time = Some(result.part_one_time);
+            row.part_two_time = Some(result.part_two_time);
+            if iai {
+                match bench_day(&entry.year, &entry.day, BenchBackend::Iai) {
+                    Ok(stats) => {
+                        row.instructions = stats.as_deref().and_then(report::part_instructions)
+                    }
+                    Err(err) => eprintln!("Day {} bench failed: {}", entry.day, err),
+                }
+            }
+        }
+        rows.push(row);
+    }
+    let table = report::format_results_table(&rows, iai);
+    let written = std::fs::read_to_string(output)
+        .or_else(|err| match err.kind() {
+            io::ErrorKind::NotFound => Ok(String::new()),
+            _ => Err(err),
+        })
+        .and_then(|contents| std::fs::write(output, report::replace_section(&contents, &table)));
+    match written {
+        Ok(()) => println!("Wrote results for {} to {}", year, output.display()),
+        Err(err) => {
+            eprintln!("Couldn't write {}: {}", output.display(), err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// A client for the Advent of Code server, set up from `aoc.toml` and the environment.
+fn aoc_client() -> Client {
+    Config::load(&default_config_path())
+        .and_then(|config| Client::new(&config))
+        .unwrap_or_else(|err| {
+            eprintln!("Couldn't set up a client: {}", err);
+            std::process::exit(1);
+        })
+        .with_last_request_path(Path::new(env!("CARGO_MANIFEST_DIR")).join(".aoc-last-request"))
+}
+
+fn fetch_days(year: i32, days: &[i32]) {
+    let mut client = aoc_client();
+    let mut failed = false;
+    for &day in days {
+        let input = match registry::find(year, day) {
+            Some(entry) => entry.input_path(),
+            None => registry::input_path(year, &day.to_string()),
+        };
+        let path = default_inputs_dir().join(input);
+        match fetch_input_to(&mut client, year, day, &path) {
+            Ok(FetchOutcome::Downloaded) => println!("Day {}: wrote {}", day, path.display()),
+            Ok(FetchOutcome::AlreadyPresent) => {
+                println!("Day {}: {} already has an input", day, path.display())
+            }
+            Err(err) => {
+                eprintln!("Day {}: couldn't fetch input: {}", day, err);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn submit_answer(year: i32, day: i32, part: Part, answer: Option<String>) {
+    let answer = match answer {
+        Some(answer) => Answer::from(answer),
+        None => {
+            let entry = find_day(year, day);
+            let options = RunOptions {
+                part: Some(part),
+                ..RunOptions::default()
+            };
+            let outcome = run_day(entry, &InputSource::default(), &options);
+            let answer = match &outcome {
+                DayOutcome::Ran(Ok(result), _) => match part {
+                    Part::One => result.part_one.clone(),
+                    Part::Two => result.part_two.clone(),
+                },
+                _ => None,
+            };
+            answer.unwrap_or_else(|| {
+                eprintln!(
+                    "Day {} didn't produce an answer ({})",
+                    day,
+                    outcome.status()
+                );
+                std::process::exit(1);
+            })
+        }
+    };
+    let path = default_submissions_path(year);
+    let mut submissions = Submissions::load(&path).unwrap_or_else(|err| {
+        eprintln!("Couldn't load submission history: {}", err);
+        std::process::exit(1);
+    });
+    let part = part.number();
+    if !answer.is_multiline() {
+        println!("Day {} part {}: submitting {}", day, part, answer);
+    }
+    let mut client = aoc_client();
+    match submit(
+        &mut client,
+        &mut submissions,
+        &path,
+        year,
+        day,
+        part,
+        &answer,
+    ) {
+        Ok(Verdict::Correct) => println!("Correct!"),
+        Ok(verdict) => {
+            println!("Not accepted: {}", verdict);
+            std::process::exit(1);
+        }
+        Err(err) => {
+            eprintln!("Day {} part {}: {}", day, part, err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// How long a downloaded leaderboard is reused for, as Advent of Code asks for no more than one
+/// request every 15 minutes.
+const LEADERBOARD_CACHE_TIME: Duration = Duration::from_secs(15 * 60);
+
+/// Download a leaderboard's JSON, or reuse the copy downloaded in the last 15 minutes.
+fn download_leaderboard(year: i32, id: Option<String>) -> Result<String, String> {
+    let config = Config::load(&default_config_path()).map_err(|err| err.to_string())?;
+    let id = id
+        .or(config.leaderboard.clone())
+        .ok_or("no leaderboard id, pass --id or set `leaderboard` in aoc.toml")?;
+    let cache_path = std::env::temp_dir().join(format!("aoc_leaderboard_{}_{}.json", year, id));
+    let fresh = std::fs::metadata(&cache_path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age < LEADERBOARD_CACHE_TIME);
+    if fresh {
+        if let Ok(json) = std::fs::read_to_string(&cache_path) {
+            return Ok(json);
+        }
+    }
+    let json = Client::new(&config)
+        .map(|client| {
+            client.with_last_request_path(
+                Path::new(env!("CARGO_MANIFEST_DIR")).join(".aoc-last-request"),
+            )
+        })
+        .and_then(|mut client| client.fetch_leaderboard(year, &id))
+        .map_err(|err| err.to_string())?;
+    // Only a cache, so it's fine if it can't be written.
+    let _ = std::fs::write(&cache_path, &json);
+    Ok(json)
 }
 
-fn days() -> Vec<i32> {
-    (0..=25).collect()
+fn show_leaderboard(
+    year: i32,
+    file: Option<PathBuf>,
+    id: Option<String>,
+    sort: SortBy,
+    day: Option<u32>,
+) {
+    let json = match file {
+        Some(path) => {
+            std::fs::read_to_string(&path).map_err(|err| format!("{}: {}", path.display(), err))
+        }
+        None => download_leaderboard(year, id),
+    };
+    let leaderboard = json
+        .and_then(|json| Leaderboard::parse(&json).map_err(|err| err.to_string()))
+        .unwrap_or_else(|err| {
+            eprintln!("Couldn't load leaderboard: {}", err);
+            std::process::exit(1);
+        });
+    print!("{}", leaderboard.format_standings(sort));
+    let days = match day {
+        Some(day) => vec![day],
+        None => leaderboard.days(),
+    };
+    for day in days {
+        println!();
+        print!("{}", leaderboard.format_day(day, sort));
+    }
+}
+
+fn watch_day(year: i32, day: i32, args: &[String]) -> ! {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let paths = watch::watched_paths(root, &InputSource::default(), year, day);
+    loop {
+        let snapshot = watch::Snapshot::take(&paths);
+        watch::clear_screen();
+        println!("Day {} examples:", day);
+        let tests = watch::run_tests(root, year, day);
+        println!("==========");
+        match tests {
+            Ok(true) => println!("Day {} examples PASSED", day),
+            Ok(false) => println!("Day {} examples FAILED", day),
+            Err(err) => println!("Couldn't run cargo test: {}", err),
+        }
+        if let Err(err) = watch::run_solution(root, year, day, args) {
+            println!("Couldn't run cargo run: {}", err);
+        }
+        println!("==========");
+        println!("Watching for changes to:");
+        for path in &paths {
+            println!("  {}", path.strip_prefix(root).unwrap_or(path).display());
+        }
+        watch::wait_for_change(&paths, &snapshot);
+    }
 }
 
 fn main() {
     let cli = Cli::parse();
-    let mut days = days();
-    let mut days_to_execute = vec![];
-    if let Some(day) = cli.day {
-        if !days.contains(&day) {
-            panic!("Day not found");
+    match cli.command {
+        Some(Command::New { day, title }) => {
+            new_day(cli.year, day, title);
+            return;
         }
-        days_to_execute.push(day);
+        Some(Command::Watch { day, args }) => watch_day(cli.year, day, &args),
+        Some(Command::Fetch { days }) => {
+            fetch_days(cli.year, &days);
+            return;
+        }
+        Some(Command::Submit { day, part, answer }) => {
+            submit_answer(cli.year, day, part, answer);
+            return;
+        }
+        Some(Command::Leaderboard {
+            file,
+            id,
+            sort,
+            day,
+        }) => {
+            show_leaderboard(cli.year, file, id, sort, day);
+            return;
+        }
+        Some(Command::Report { output, iai }) => {
+            // Relative to the crate root, like the other files this writes.
+            let output = Path::new(env!("CARGO_MANIFEST_DIR")).join(output);
+            write_report(cli.year, &output, iai);
+            return;
+        }
+        None => {}
+    }
+    if !registry::years().contains(&cli.year) {
+        eprintln!(
+            "No days registered for {} (registered years: {:?})",
+            cli.year,
+            registry::years()
+        );
+        std::process::exit(1);
+    }
+    if cli.list {
+        list_days(cli.year);
+        return;
+    }
+    let days_to_execute = if let Some(day) = cli.day {
+        vec![find_day(cli.year, day)]
     } else {
-        days_to_execute = days.drain(1..).collect(); // Skip day0 example
+        // Skip day0 example
+        registry::days(cli.year)
+            .into_iter()
+            .filter(|entry| entry.day != 0)
+            .collect()
+    };
+    if let Some(backend) = cli.bench {
+        bench_days(&days_to_execute, backend);
+        return;
+    }
+
+    let source = cli.input_source();
+    let jobs = match cli.jobs {
+        0 => max_jobs(),
+        jobs if jobs > max_jobs() => {
+            eprintln!(
+                "Only using {} jobs, one per CPU, to keep timings accurate",
+                max_jobs()
+            );
+            max_jobs()
+        }
+        jobs => jobs,
+    };
+    let options = RunOptions {
+        part: cli.part,
+        repeat: cli.repeat.max(1),
+        warmup: if cli.repeat > 1 { cli.warmup } else { 0 },
+    };
+    let mut reporter = Reporter {
+        format: cli.format,
+        include_time: cli.time,
+        part: cli.part,
+        answer_store: cli.check.then(|| {
+            AnswerStore::load(&default_answers_path(cli.year)).unwrap_or_else(|err| {
+                eprintln!("Couldn't load answers: {}", err);
+                std::process::exit(1);
+            })
+        }),
+        check_summary: CheckSummary::default(),
+        failed_days: vec![],
+        summary: vec![],
+        history: cli.time.then(|| {
+            let path = default_history_path(cli.year);
+            TimingHistory {
+                recorded: History::load(&path).unwrap_or_else(|err| {
+                    eprintln!("Couldn't load timing history: {}", err);
+                    std::process::exit(1);
+                }),
+                path,
+                commit: history::current_commit(),
+                compare: cli.compare,
+                threshold: cli.threshold / 100.0,
+                new_records: vec![],
+                comparisons: vec![],
+                regressions: 0,
+            }
+        }),
+    };
+    if cli.format == OutputFormat::Csv {
+        println!("{}", DayRecord::CSV_HEADER);
     }
-    for day in days_to_execute {
-        if cli.bench {
-            bench_day(&day);
+    run_in_order(
+        &days_to_execute,
+        jobs,
+        |entry| run_day(entry, &source, &options),
+        |entry, outcome| reporter.report(entry, outcome),
+    );
+    if cli.format == OutputFormat::Text && reporter.summary.len() > 1 {
+        println!("==========");
+        print!("{}", format_summary_table(&reporter.summary));
+    }
+    let mut failed = false;
+    if !reporter.failed_days.is_empty() {
+        failed = true;
+        let summary = format!(
+            "{} of {} days FAILED: {}",
+            reporter.failed_days.len(),
+            days_to_execute.len(),
+            reporter
+                .failed_days
+                .iter()
+                .map(i32::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        // Keep machine-readable output parseable.
+        if cli.format == OutputFormat::Text {
+            println!("==========");
+            println!("{}", summary);
         } else {
-            solve_day(&day, !cli.time);
+            eprintln!("{}", summary);
+        }
+    }
+    if let Some(history) = &reporter.history {
+        if let Err(err) = History::append(&history.path, &history.new_records) {
+            eprintln!("Couldn't record timings: {}", err);
+        }
+        if history.compare.is_some() && history.regressions > 0 {
+            failed = true;
+            println!("==========");
+            println!(
+                "{} timings REGRESSED by more than {}%",
+                history.regressions, cli.threshold
+            );
         }
     }
+    if reporter.answer_store.is_some() {
+        let summary = &reporter.check_summary;
+        println!("==========");
+        println!(
+            "Checked parts: {} passed, {} failed, {} missing",
+            summary.passed, summary.failed, summary.missing
+        );
+        failed |= summary.failed > 0;
+    }
+    if failed {
+        std::process::exit(1);
+    }
 }
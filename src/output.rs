@@ -0,0 +1,180 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{error::AocResult, registry::DayEntry, SolveResult};
+
+/// How results are written to stdout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable answers, as printed by `SolveResult::print`.
+    #[default]
+    Text,
+    /// One JSON object per day, one per line.
+    Json,
+    /// A header row followed by one row per day.
+    Csv,
+}
+
+/// A machine-readable record of one day's run. Failed days have no answers or timings, but an error.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DayRecord {
+    pub year: i32,
+    pub day: i32,
+    pub title: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub part1_ns: Option<u64>,
+    pub part2_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl DayRecord {
+    pub const CSV_HEADER: &'static str =
+        "year,day,title,part1,part2,parse_ns,part1_ns,part2_ns,error";
+
+    pub fn new(entry: &DayEntry, result: &AocResult<SolveResult>) -> Self {
+        match result {
+            Ok(result) => Self {
+                part1: result.part_one.as_ref().map(ToString::to_string),
+                part2: result.part_two.as_ref().map(ToString::to_string),
+                parse_ns: Some(result.parse_time.as_nanos() as u64),
+                part1_ns: result
+                    .part_one
+                    .as_ref()
+                    .map(|_| result.part_one_time.as_nanos() as u64),
+                part2_ns: result
+                    .part_two
+                    .as_ref()
+                    .map(|_| result.part_two_time.as_nanos() as u64),
+                ..Self::empty(entry)
+            },
+            Err(err) => Self::failed(entry, err),
+        }
+    }
+
+    /// A record for a day that produced no answers, e.g. because its input couldn't be loaded.
+    pub fn failed(entry: &DayEntry, error: impl ToString) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..Self::empty(entry)
+        }
+    }
+
+    fn empty(entry: &DayEntry) -> Self {
+        Self {
+            year: entry.year,
+            day: entry.day,
+            title: entry.title.to_string(),
+            part1: None,
+            part2: None,
+            parse_ns: None,
+            part1_ns: None,
+            part2_ns: None,
+            error: None,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("DayRecord is always serializable")
+    }
+
+    pub fn to_csv(&self) -> String {
+        let optional = |value: &Option<String>| value.as_deref().map(csv_field).unwrap_or_default();
+        let number = |value: Option<u64>| value.map(|n| n.to_string()).unwrap_or_default();
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            csv_field(&self.title),
+            optional(&self.part1),
+            optional(&self.part2),
+            number(self.parse_ns),
+            number(self.part1_ns),
+            number(self.part2_ns),
+            optional(&self.error),
+        ]
+        .join(",")
+    }
+}
+
+/// Lay out rows of cells as a plain-text table, the first row being the header. Columns are two spaces
+/// apart and right-aligned, except `left_aligned`, which suits a column of words among numbers.
+pub fn format_table<const N: usize>(rows: &[[String; N]], left_aligned: usize) -> String {
+    let widths = (0..N)
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    let mut table = String::new();
+    for row in rows {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(col, (cell, width))| {
+                if col == left_aligned {
+                    format!("{:<width$}", cell, width = width)
+                } else {
+                    format!("{:>width$}", cell, width = width)
+                }
+            })
+            .collect::<Vec<_>>();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+/// Quote a CSV field if it contains a separator, quote or newline (e.g. a multi-line answer).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{error::AocError, registry, Answer};
+
+    fn result() -> SolveResult {
+        SolveResult {
+            part_one: Some(Answer::from(24000)),
+            part_two: Some(Answer::grid(["#.", ".#"])),
+            parse_time: Duration::from_nanos(1500),
+            part_one_time: Duration::from_micros(2),
+            part_two_time: Duration::from_nanos(30),
+            stats: None,
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn check_record_json() {
+        let entry = registry::find(2022, 1).unwrap();
+        assert_eq!(
+            DayRecord::new(entry, &Ok(result())).to_json(),
+            r##"{"year":2022,"day":1,"title":"Calorie Counting","part1":"24000","part2":"#.\n.#","parse_ns":1500,"part1_ns":2000,"part2_ns":30,"error":null}"##
+        )
+    }
+
+    #[test]
+    fn check_record_csv() {
+        let entry = registry::find(2022, 1).unwrap();
+        assert_eq!(
+            DayRecord::new(entry, &Ok(result())).to_csv(),
+            "2022,1,Calorie Counting,24000,\"#.\n.#\",1500,2000,30,"
+        );
+        assert_eq!(
+            DayRecord::new(entry, &Err(AocError::parse(0, "x", "bad, very bad"))).to_csv(),
+            "2022,1,Calorie Counting,,,,,,\"line 1: bad, very bad (in \"\"x\"\")\""
+        );
+    }
+}
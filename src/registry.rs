@@ -0,0 +1,155 @@
+use std::{io, path::PathBuf};
+
+use crate::{error::AocResult, input::InputSource, timing::RunOptions, SolveResult, DEFAULT_YEAR};
+
+/// A day's solution, registered from its own module with `register_day!`.
+#[derive(Debug)]
+pub struct DayEntry {
+    pub year: i32,
+    pub day: i32,
+    pub title: &'static str,
+    /// Name of the input file within the year's inputs directory.
+    pub input: &'static str,
+    /// False for days still holding the untouched template, which are reported rather than run.
+    pub implemented: bool,
+    pub run: fn(&str, &RunOptions) -> AocResult<SolveResult>,
+    #[cfg(feature = "criterion")]
+    pub criterion: fn(&mut criterion::Criterion, &DayEntry),
+}
+
+inventory::collect!(DayEntry);
+
+impl DayEntry {
+    pub fn input_path(&self) -> PathBuf {
+        input_path(self.year, self.input)
+    }
+
+    pub fn load_input(&self, source: &InputSource) -> io::Result<String> {
+        source.load(&self.input_path())
+    }
+
+    pub fn bench_name(&self) -> String {
+        bench_name(self.year, self.day)
+    }
+}
+
+/// Path of an input file relative to the inputs directory: `<input>` for the default year and
+/// `<year>/<input>` for any other year.
+pub fn input_path(year: i32, input: &str) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        PathBuf::from(input)
+    } else {
+        PathBuf::from(year.to_string()).join(input)
+    }
+}
+
+/// Name of a day's iai bench target: `dayNN` for the default year, `yYYYY_dayNN` otherwise.
+pub fn bench_name(year: i32, day: i32) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day{:02}", day)
+    } else {
+        format!("y{}_day{:02}", year, day)
+    }
+}
+
+/// Register a `Solution` so it can be found by `bench_day` and the CLI.
+///
+/// ```ignore
+/// register_day!(Day01, 2022, 1, "Calorie Counting");
+/// register_day!(Day01, 2022, 1, "Calorie Counting", input = "1-alternative");
+/// register_day!(Day14, 2022, 14, "Regolith Reservoir", unimplemented);
+/// ```
+#[macro_export]
+macro_rules! register_day {
+    ($solution:ty, $year:literal, $day:literal, $title:literal) => {
+        $crate::register_day!(@entry $solution, $year, $day, $title, concat!($day), true);
+    };
+    ($solution:ty, $year:literal, $day:literal, $title:literal, input = $input:literal) => {
+        $crate::register_day!(@entry $solution, $year, $day, $title, $input, true);
+    };
+    ($solution:ty, $year:literal, $day:literal, $title:literal, unimplemented) => {
+        $crate::register_day!(@entry $solution, $year, $day, $title, concat!($day), false);
+    };
+    (@entry $solution:ty, $year:expr, $day:expr, $title:expr, $input:expr, $implemented:expr) => {
+        $crate::inventory::submit! {
+            $crate::registry::DayEntry {
+                year: $year,
+                day: $day,
+                title: $title,
+                input: $input,
+                implemented: $implemented,
+                run: <$solution as $crate::Solution>::try_run_with,
+                #[cfg(feature = "criterion")]
+                criterion: $crate::bench::criterion_benches::<$solution>,
+            }
+        }
+    };
+}
+
+/// All registered days of a year, in day order.
+pub fn days(year: i32) -> Vec<&'static DayEntry> {
+    let mut days = inventory::iter::<DayEntry>
+        .into_iter()
+        .filter(|entry| entry.year == year)
+        .collect::<Vec<_>>();
+    days.sort_by_key(|entry| entry.day);
+    days
+}
+
+/// Every year with at least one registered day, in order.
+pub fn years() -> Vec<i32> {
+    let mut years = inventory::iter::<DayEntry>
+        .into_iter()
+        .map(|entry| entry.year)
+        .collect::<Vec<_>>();
+    years.sort();
+    years.dedup();
+    years
+}
+
+pub fn find(year: i32, day: i32) -> Option<&'static DayEntry> {
+    inventory::iter::<DayEntry>
+        .into_iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_registry_sorted_and_unique() {
+        let days = days(2022).iter().map(|entry| entry.day).collect::<Vec<_>>();
+        assert_eq!(days, (0..=25).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn check_registry_find() {
+        let entry = find(2022, 1).unwrap();
+        assert_eq!(entry.title, "Calorie Counting");
+        assert_eq!(entry.input, "1");
+        assert!(entry.implemented);
+        assert!(!find(2022, 25).unwrap().implemented);
+        assert!(find(2022, 26).is_none());
+        assert!(find(2015, 1).is_none());
+    }
+
+    #[test]
+    fn check_registry_paths() {
+        let entry = DayEntry {
+            year: 2023,
+            day: 7,
+            title: "",
+            input: "7",
+            implemented: true,
+            run: |_, _| unreachable!(),
+            #[cfg(feature = "criterion")]
+            criterion: |_, _| unreachable!(),
+        };
+        assert_eq!(entry.input_path(), PathBuf::from("2023/7"));
+        assert_eq!(entry.bench_name(), "y2023_day07");
+        let entry = find(DEFAULT_YEAR, 7).unwrap();
+        assert_eq!(entry.input_path(), PathBuf::from("7"));
+        assert_eq!(entry.bench_name(), "day07");
+    }
+}
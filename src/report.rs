@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use crate::{bench::IaiStats, summary::DayStatus};
+
+/// Marks the start of the generated results section in a Markdown file.
+pub const START_MARKER: &str = "<!-- results:start -->";
+/// Marks the end of the generated results section in a Markdown file.
+pub const END_MARKER: &str = "<!-- results:end -->";
+
+/// One day's row in the results table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReportRow {
+    pub day: i32,
+    pub title: String,
+    pub status: DayStatus,
+    pub part_one_time: Option<Duration>,
+    pub part_two_time: Option<Duration>,
+    /// iai instruction counts for part one and part two, if benchmarked.
+    pub instructions: Option<[u64; 2]>,
+}
+
+impl ReportRow {
+    pub fn new(day: i32, title: &str, status: DayStatus) -> Self {
+        Self {
+            day,
+            title: title.to_string(),
+            status,
+            part_one_time: None,
+            part_two_time: None,
+            instructions: None,
+        }
+    }
+}
+
+/// The instruction counts for part one and part two from a day's iai results. The part benches load and
+/// parse the input first, so the `parsing` bench's count is taken off to leave just the part itself.
+pub fn part_instructions(stats: &[IaiStats]) -> Option<[u64; 2]> {
+    let instructions = |name: &str| {
+        stats
+            .iter()
+            .find(|stat| stat.name == name)
+            .map(|stat| stat.instructions)
+    };
+    let parsing = instructions("parsing")?;
+    Some([
+        instructions("part_one")?.saturating_sub(parsing),
+        instructions("part_two")?.saturating_sub(parsing),
+    ])
+}
+
+/// Format the rows as a Markdown table, with instruction count columns if `with_instructions`.
+pub fn format_results_table(rows: &[ReportRow], with_instructions: bool) -> String {
+    let mut table = String::from("| Day | Title | Status | Part 1 | Part 2 |");
+    if with_instructions {
+        table.push_str(" Part 1 instructions | Part 2 instructions |");
+    }
+    table.push_str("\n| --: | :-- | :-- | --: | --: |");
+    if with_instructions {
+        table.push_str(" --: | --: |");
+    }
+    table.push('\n');
+    let time = |time: Option<Duration>| {
+        time.map_or_else(|| "-".to_string(), |time| format!("{:.2?}", time))
+    };
+    for row in rows {
+        table.push_str(&format!(
+            "| {} | {} | {} | {} | {} |",
+            row.day,
+            row.title.replace('|', "\\|"),
+            row.status,
+            time(row.part_one_time),
+            time(row.part_two_time)
+        ));
+        if with_instructions {
+            match row.instructions {
+                Some([part_one, part_two]) => {
+                    table.push_str(&format!(" {} | {} |", part_one, part_two))
+                }
+                None => table.push_str(" - | - |"),
+            }
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// Position of `marker` where it's on a line of its own, so that markers quoted in prose are skipped.
+fn find_marker(contents: &str, marker: &str) -> Option<usize> {
+    contents
+        .match_indices(marker)
+        .map(|(idx, _)| idx)
+        .find(|&idx| {
+            let rest = &contents[idx + marker.len()..];
+            (idx == 0 || contents[..idx].ends_with('\n'))
+                && (rest.is_empty() || rest.starts_with('\n') || rest.starts_with("\r\n"))
+        })
+}
+
+/// Replace the text between the results markers in `contents` with `table`. If the markers aren't
+/// there yet, the section is added to the end.
+pub fn replace_section(contents: &str, table: &str) -> String {
+    let section = format!("{}\n{}{}", START_MARKER, table, END_MARKER);
+    match (
+        find_marker(contents, START_MARKER),
+        find_marker(contents, END_MARKER),
+    ) {
+        (Some(start), Some(end)) if start < end => format!(
+            "{}{}{}",
+            &contents[..start],
+            section,
+            &contents[end + END_MARKER.len()..]
+        ),
+        _ if contents.trim().is_empty() => format!("{}\n", section),
+        _ => format!("{}\n{}\n", contents.trim_end(), section),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_format_results_table() {
+        let rows = [
+            ReportRow {
+                part_one_time: Some(Duration::from_micros(20)),
+                part_two_time: Some(Duration::from_micros(1500)),
+                instructions: Some([1000, 2000]),
+                ..ReportRow::new(1, "Calorie Counting", DayStatus::Solved)
+            },
+            ReportRow::new(14, "Regolith Reservoir", DayStatus::Template),
+        ];
+        assert_eq!(
+            format_results_table(&rows, false),
+            "| Day | Title | Status | Part 1 | Part 2 |
+| --: | :-- | :-- | --: | --: |
+| 1 | Calorie Counting | solved | 20.00µs | 1.50ms |
+| 14 | Regolith Reservoir | template | - | - |
+"
+        );
+        assert!(format_results_table(&rows, true)
+            .contains("| 1 | Calorie Counting | solved | 20.00µs | 1.50ms | 1000 | 2000 |"));
+    }
+
+    #[test]
+    fn check_replace_section() {
+        let readme = format!(
+            "# Title\nSee `{}`.\n{}\nold\n{}\n\nMore\n",
+            START_MARKER, START_MARKER, END_MARKER
+        );
+        assert_eq!(
+            replace_section(&readme, "new\n"),
+            format!(
+                "# Title\nSee `{}`.\n{}\nnew\n{}\n\nMore\n",
+                START_MARKER, START_MARKER, END_MARKER
+            )
+        );
+        assert_eq!(
+            replace_section("# Title\n", "new\n"),
+            format!("# Title\n{}\nnew\n{}\n", START_MARKER, END_MARKER)
+        );
+        assert_eq!(
+            replace_section("", "new\n"),
+            format!("{}\nnew\n{}\n", START_MARKER, END_MARKER)
+        );
+    }
+
+    #[test]
+    fn check_part_instructions() {
+        let stat = |name: &str, instructions| IaiStats {
+            name: name.to_string(),
+            instructions,
+            l1_accesses: 0,
+            l2_accesses: 0,
+            ram_accesses: 0,
+            estimated_cycles: 0,
+        };
+        let stats = [
+            stat("parsing", 5000),
+            stat("part_one", 6000),
+            stat("part_two", 9000),
+        ];
+        assert_eq!(part_instructions(&stats), Some([1000, 4000]));
+        assert_eq!(part_instructions(&stats[1..]), None);
+    }
+}
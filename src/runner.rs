@@ -0,0 +1,166 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Once,
+    },
+    thread,
+};
+
+/// Number of jobs to use for `--jobs 0`, and the most that are worth running at once: beyond one
+/// per CPU, days would compete for CPU time and their wall-clock timings would be inflated.
+pub fn max_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |jobs| jobs.get())
+}
+
+/// Run `work` on every item using up to `jobs` threads, passing each result to `report` in the
+/// original order of `items`, as soon as it and every result before it is ready.
+pub fn run_in_order<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut report: impl FnMut(&T, R),
+) {
+    if jobs <= 1 {
+        for item in items {
+            report(item, work(item));
+        }
+        return;
+    }
+    let work = &work;
+    let next_item = &AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            scope.spawn(move || loop {
+                let idx = next_item.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(idx) else {
+                    break;
+                };
+                if sender.send((idx, work(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_to_report = 0;
+        for (idx, result) in receiver {
+            pending.insert(idx, result);
+            while let Some(result) = pending.remove(&next_to_report) {
+                report(&items[next_to_report], result);
+                next_to_report += 1;
+            }
+        }
+    });
+}
+
+thread_local! {
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Run `f`, turning a panic into an `Err` with the panic's message and location instead of
+/// unwinding further. The panic isn't printed, so the caller can report it however it likes.
+pub fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    static INSTALL_HOOK: Once = Once::new();
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING_PANICS.with(Cell::get) {
+                let message = match info.location() {
+                    Some(location) => format!(
+                        "panicked at {}: {}",
+                        location,
+                        payload_message(info.payload())
+                    ),
+                    None => format!("panicked: {}", payload_message(info.payload())),
+                };
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+            } else {
+                default_hook(info)
+            }
+        }));
+    });
+
+    let was_catching = CATCHING_PANICS.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_PANICS.with(|catching| catching.set(was_catching));
+    result.map_err(|payload| {
+        LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| format!("panicked: {}", payload_message(payload.as_ref())))
+    })
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn check_run_in_order_reports_in_order() {
+        let items = (0..20).collect::<Vec<u64>>();
+        let mut reported = vec![];
+        run_in_order(
+            &items,
+            4,
+            |item| {
+                // Make earlier items finish last.
+                thread::sleep(Duration::from_millis(20 - item));
+                item * 2
+            },
+            |item, result| reported.push((*item, result)),
+        );
+        assert_eq!(
+            reported,
+            items
+                .iter()
+                .map(|item| (*item, item * 2))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn check_run_in_order_single_job() {
+        let mut reported = vec![];
+        run_in_order(
+            &[1, 2, 3],
+            1,
+            |item| item + 1,
+            |_, result| reported.push(result),
+        );
+        assert_eq!(reported, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn check_catch_panic() {
+        assert_eq!(catch_panic(|| 1 + 1), Ok(2));
+        let err = catch_panic(|| {
+            let empty: Vec<i32> = vec![];
+            empty[0]
+        })
+        .unwrap_err();
+        assert!(err.starts_with("panicked at src/runner.rs:"), "{}", err);
+        assert!(err.ends_with("index out of bounds: the len is 0 but the index is 0"));
+        let err = catch_panic(|| -> i32 { panic!("day {} broke", 12) }).unwrap_err();
+        assert!(err.starts_with("panicked at src/runner.rs:"), "{}", err);
+        assert!(err.ends_with(": day 12 broke"), "{}", err);
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::registry;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+const BENCH_TEMPLATE: &str = include_str!("../templates/bench.rs.tmpl");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Io(io::Error),
+    /// Advent of Code only has days 1 to 25.
+    InvalidDay(i32),
+    /// The day's module has been changed from the template, so it won't be overwritten.
+    AlreadyImplemented(PathBuf),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{}", err),
+            Self::InvalidDay(day) => write!(f, "day {} isn't between 1 and 25", day),
+            Self::AlreadyImplemented(path) => write!(
+                f,
+                "{} already has code that isn't from the template, not overwriting it",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Generate everything a new day needs within the crate at `root`: its module (registered as
+/// unimplemented), the `pub mod` lines for it and its year, an iai bench with its `[[bench]]` entry
+/// in `Cargo.toml`, and an empty input file.
+///
+/// A day module that is still the untouched template is regenerated, e.g. to change its title, but
+/// one with any other changes is left alone and an error is returned. Everything else is only
+/// created if it's missing. Returns the files that were written.
+pub fn new_day(
+    root: &Path,
+    year: i32,
+    day: i32,
+    title: &str,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+    let year_module = format!("year{}", year);
+    let year_dir = root.join("src").join(&year_module);
+    let day_path = year_dir.join(format!("day{:02}.rs", day));
+    if let Some(existing) = read_if_exists(&day_path)? {
+        if !is_template(&existing, year, day) {
+            return Err(ScaffoldError::AlreadyImplemented(day_path));
+        }
+    }
+
+    let mut written = vec![];
+    fs::create_dir_all(&year_dir)?;
+    fs::write(&day_path, render(DAY_TEMPLATE, year, day, title))?;
+    written.push(day_path);
+
+    let mod_path = year_dir.join("mod.rs");
+    if add_mod_line(&mod_path, &format!("day{:02}", day))? {
+        written.push(mod_path);
+    }
+    let lib_path = root.join("src").join("lib.rs");
+    if add_mod_line(&lib_path, &year_module)? {
+        written.push(lib_path);
+    }
+
+    let bench_name = registry::bench_name(year, day);
+    let bench_path = root.join("benches").join(format!("{}.rs", bench_name));
+    if read_if_exists(&bench_path)?.is_none() {
+        fs::create_dir_all(root.join("benches"))?;
+        fs::write(&bench_path, render(BENCH_TEMPLATE, year, day, title))?;
+        written.push(bench_path);
+    }
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    if !manifest.contains(&format!("name = \"{}\"", bench_name)) {
+        let separator = if manifest.ends_with('\n') {
+            "\n"
+        } else {
+            "\n\n"
+        };
+        fs::write(
+            &manifest_path,
+            format!(
+                "{}{}[[bench]]\nname = \"{}\"\nharness = false\n",
+                manifest, separator, bench_name
+            ),
+        )?;
+        written.push(manifest_path);
+    }
+
+    let input_path = root
+        .join("inputs")
+        .join(registry::input_path(year, &day.to_string()));
+    if read_if_exists(&input_path)?.is_none() {
+        fs::create_dir_all(input_path.parent().unwrap())?;
+        fs::write(&input_path, "")?;
+        written.push(input_path);
+    }
+    Ok(written)
+}
+
+fn read_if_exists(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn render(template: &str, year: i32, day: i32, title: &str) -> String {
+    template
+        .replace("{{struct}}", &format!("Day{:02}", day))
+        .replace("{{module}}", &format!("day{:02}", day))
+        .replace("{{year_module}}", &format!("year{}", year))
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{title}}", &format!("{:?}", title))
+}
+
+/// Whether a day module is the template for that day, whatever its title.
+fn is_template(contents: &str, year: i32, day: i32) -> bool {
+    without_registration(contents) == without_registration(&render(DAY_TEMPLATE, year, day, ""))
+}
+
+fn without_registration(code: &str) -> Vec<&str> {
+    code.lines()
+        .filter(|line| !line.contains("register_day!"))
+        .map(str::trim_end)
+        .collect()
+}
+
+/// Add `pub mod <module>;` to the block of `pub mod` lines in a file, keeping them sorted and creating
+/// the file if it's missing. Returns whether the file changed.
+fn add_mod_line(path: &Path, module: &str) -> io::Result<bool> {
+    let line = format!("pub mod {};", module);
+    let contents = read_if_exists(path)?.unwrap_or_default();
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    if lines.contains(&line.as_str()) {
+        return Ok(false);
+    }
+    let idx = lines
+        .iter()
+        .position(|existing| existing.starts_with("pub mod ") && *existing > line.as_str())
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|existing| existing.starts_with("pub mod "))
+                .map(|idx| idx + 1)
+        })
+        .unwrap_or(lines.len());
+    lines.insert(idx, &line);
+    fs::write(path, lines.join("\n") + "\n")?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crate_root(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc_scaffold_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub mod answers;\npub mod year2022;\n",
+        )
+        .unwrap();
+        fs::write(root.join("Cargo.toml"), "[package]\nname = \"x\"\n").unwrap();
+        root
+    }
+
+    #[test]
+    fn check_existing_templates_are_templates() {
+        let day14 = include_str!("year2022/day14.rs");
+        assert!(is_template(day14, 2022, 14));
+        assert!(!is_template(include_str!("year2022/day13.rs"), 2022, 13));
+        assert!(!is_template(
+            &day14.replace("0.into()", "1.into()"),
+            2022,
+            14
+        ));
+    }
+
+    #[test]
+    fn check_new_day() {
+        let root = crate_root("new_day");
+        let written = new_day(&root, 2023, 7, "Camel \"Cards\"").unwrap();
+        assert_eq!(written.len(), 6);
+        let module = fs::read_to_string(root.join("src/year2023/day07.rs")).unwrap();
+        assert!(module.contains(
+            r#"crate::register_day!(Day07, 2023, 7, "Camel \"Cards\"", unimplemented);"#
+        ));
+        assert_eq!(
+            fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            "pub mod answers;\npub mod year2022;\npub mod year2023;\n"
+        );
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .ends_with("\n[[bench]]\nname = \"y2023_day07\"\nharness = false\n"));
+        assert!(root.join("benches/y2023_day07.rs").exists());
+        assert!(root.join("inputs/2023/7").exists());
+
+        // Regenerating an untouched template only rewrites the module.
+        assert_eq!(
+            new_day(&root, 2023, 7, "Camel Cards").unwrap(),
+            vec![root.join("src/year2023/day07.rs")]
+        );
+        fs::write(
+            root.join("src/year2023/day07.rs"),
+            module.replace("0.into()", "1.into()"),
+        )
+        .unwrap();
+        assert!(matches!(
+            new_day(&root, 2023, 7, "Camel Cards"),
+            Err(ScaffoldError::AlreadyImplemented(_))
+        ));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
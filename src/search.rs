@@ -0,0 +1,268 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The result of a search over an implicit graph: the cost of reaching each node that was
+/// reached, the way it was reached, and the first goal node found, if any.
+#[derive(Clone, Debug)]
+pub struct Search<N, C> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    costs: Vec<C>,
+    parents: Vec<Option<usize>>,
+    goal: Option<usize>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Self {
+            nodes: vec![],
+            index: HashMap::new(),
+            costs: vec![],
+            parents: vec![],
+            goal: None,
+        }
+    }
+
+    /// Record a node's cost and parent, returning its index.
+    fn insert(&mut self, node: N, cost: C, parent: Option<usize>) -> usize {
+        match self.index.get(&node) {
+            Some(&idx) => {
+                self.costs[idx] = cost;
+                self.parents[idx] = parent;
+                idx
+            }
+            None => {
+                let idx = self.nodes.len();
+                self.index.insert(node.clone(), idx);
+                self.nodes.push(node);
+                self.costs.push(cost);
+                self.parents.push(parent);
+                idx
+            }
+        }
+    }
+
+    /// The cost of reaching `node`, if it was reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.index.get(node).map(|&idx| self.costs[idx])
+    }
+
+    /// The nodes on the way to `node`, from the start it was reached from to `node` itself.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        let mut idx = *self.index.get(node)?;
+        let mut path = vec![self.nodes[idx].clone()];
+        while let Some(parent) = self.parents[idx] {
+            path.push(self.nodes[parent].clone());
+            idx = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The first goal node found.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.map(|idx| &self.nodes[idx])
+    }
+
+    /// The cost of reaching the goal, if one was found.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goal.map(|idx| self.costs[idx])
+    }
+
+    /// The path to the goal, if one was found.
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal()?)
+    }
+
+    /// Every node reached, with its cost.
+    pub fn distances(&self) -> impl Iterator<Item = (&N, C)> {
+        self.nodes.iter().zip(self.costs.iter().copied())
+    }
+}
+
+/// Breadth-first search from `start`, where every step costs 1. Stops at the first node for which
+/// `is_goal` is true, or explores everything reachable if there isn't one.
+pub fn bfs<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], neighbours, is_goal)
+}
+
+/// Breadth-first search from several starts at once, so each node's distance is from the nearest.
+pub fn bfs_multi<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.index.contains_key(&start) {
+            queue.push_back(search.insert(start, 0, None));
+        }
+    }
+    while let Some(idx) = queue.pop_front() {
+        let node = search.nodes[idx].clone();
+        if is_goal(&node) {
+            search.goal = Some(idx);
+            break;
+        }
+        let distance = search.costs[idx] + 1;
+        for next in neighbours(&node) {
+            if !search.index.contains_key(&next) {
+                queue.push_back(search.insert(next, distance, Some(idx)));
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm from `start`, where `successors` gives each neighbour with the cost of
+/// moving to it. Stops at the first node for which `is_goal` is true, or explores everything
+/// reachable if there isn't one.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search from `start`, guided by a `heuristic` estimate of the remaining cost to a goal. The
+/// goal's distance is only the shortest if the heuristic never overestimates; other nodes'
+/// distances are the best found before the search stopped.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    let mut queue = BinaryHeap::new();
+    let estimate = heuristic(&start);
+    let start = search.insert(start, C::default(), None);
+    queue.push(Reverse((estimate, C::default(), start)));
+    while let Some(Reverse((_, cost, idx))) = queue.pop() {
+        // Skip queue entries for nodes that have since been reached more cheaply.
+        if cost > search.costs[idx] {
+            continue;
+        }
+        let node = search.nodes[idx].clone();
+        if is_goal(&node) {
+            search.goal = Some(idx);
+            break;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if search.distance(&next).is_none_or(|known| next_cost < known) {
+                let estimate = next_cost + heuristic(&next);
+                let next = search.insert(next, next_cost, Some(idx));
+                queue.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Point};
+
+    const MAZE: &str = "\
+..#.
+.##.
+....";
+
+    fn open_neighbours(maze: &Grid<char>) -> impl FnMut(&Point) -> Vec<Point> + '_ {
+        move |&point| {
+            maze.neighbours4(point)
+                .filter(|&next| maze[next] == '.')
+                .collect()
+        }
+    }
+
+    #[test]
+    fn check_bfs() {
+        let maze = Grid::parse(MAZE, |c| c);
+        let search = bfs((0, 0), open_neighbours(&maze), |&point| point == (3, 0));
+        assert_eq!(search.goal_distance(), Some(7));
+        assert_eq!(
+            search.goal_path().unwrap(),
+            vec![
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (1, 2),
+                (2, 2),
+                (3, 2),
+                (3, 1),
+                (3, 0)
+            ]
+        );
+        let everywhere = bfs((0, 0), open_neighbours(&maze), |_| false);
+        assert_eq!(everywhere.goal(), None);
+        assert_eq!(everywhere.distances().count(), 9);
+        let nearest = bfs_multi([(0, 0), (3, 0)], open_neighbours(&maze), |_| false);
+        assert_eq!(nearest.distance(&(3, 2)), Some(2));
+        assert_eq!(nearest.distance(&(2, 0)), None);
+    }
+
+    #[test]
+    fn check_dijkstra_and_astar() {
+        // Moving right along the top row is cheaper than anywhere else, so the cheapest path to
+        // the bottom right goes along the top and then down.
+        let grid = Grid::new(4, 3, ());
+        let successors = |&point: &Point| {
+            grid.neighbours4(point)
+                .map(|next| {
+                    (
+                        next,
+                        if next.1 == 0 || next.0 == point.0 {
+                            1
+                        } else {
+                            5
+                        },
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let search = dijkstra((0, 0), successors, |&point| point == (3, 2));
+        assert_eq!(search.goal_distance(), Some(5));
+        assert_eq!(
+            search.goal_path().unwrap(),
+            vec![(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (3, 2)]
+        );
+        let everywhere = dijkstra((0, 0), successors, |_| false);
+        assert_eq!(everywhere.distance(&(0, 2)), Some(2));
+        assert_eq!(everywhere.distance(&(2, 2)), Some(4));
+        let manhattan = |&(x, y): &Point| (3 - x) + (2 - y);
+        let search = astar((0, 0), successors, manhattan, |&point| point == (3, 2));
+        assert_eq!(search.goal_distance(), Some(5));
+        assert_eq!(search.goal_path().unwrap().len(), 6);
+    }
+}
@@ -0,0 +1,342 @@
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, ClientError},
+    history::read_jsonl,
+    Answer,
+};
+
+/// What the server said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, with how long is left to wait if the server said.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part has already been solved, or isn't unlocked yet.
+    WrongLevel,
+    /// The response didn't match any known message.
+    Unknown,
+}
+
+impl Verdict {
+    /// Parse the page returned after submitting an answer.
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Self::Correct
+        } else if page.contains("You gave an answer too recently") {
+            let wait = page.split("You have ").nth(1).and_then(|rest| {
+                rest.split_once(" left to wait")
+                    .map(|(wait, _)| wait.to_string())
+            });
+            Self::RateLimited { wait }
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Self::TooHigh
+            } else if page.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Incorrect
+            }
+        } else if page.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Whether the answer is known to be wrong, so mustn't be submitted again.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::Incorrect | Self::TooHigh | Self::TooLow)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Incorrect => write!(f, "incorrect"),
+            Self::TooHigh => write!(f, "incorrect, too high"),
+            Self::TooLow => write!(f, "incorrect, too low"),
+            Self::RateLimited { wait: Some(wait) } => {
+                write!(f, "submitted too recently, {} left to wait", wait)
+            }
+            Self::RateLimited { wait: None } => write!(f, "submitted too recently"),
+            Self::WrongLevel => write!(f, "part already solved or not unlocked yet"),
+            Self::Unknown => write!(f, "unrecognised response"),
+        }
+    }
+}
+
+/// One submitted answer, as stored in the submission history.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: i32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Why an answer wasn't submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part has already been solved with this answer.
+    AlreadyCorrect(String),
+    /// This exact answer has been submitted before and was wrong.
+    KnownWrong(Verdict),
+    /// The answer is at least as big as one the server said was too high.
+    AtOrAboveTooHigh(i128),
+    /// The answer is at most as small as one the server said was too low.
+    AtOrBelowTooLow(i128),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyCorrect(answer) => write!(f, "already solved with {}", answer),
+            Self::KnownWrong(verdict) => write!(f, "already submitted and was {}", verdict),
+            Self::AtOrAboveTooHigh(bound) => write!(f, "{} was already too high", bound),
+            Self::AtOrBelowTooLow(bound) => write!(f, "{} was already too low", bound),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Client(ClientError),
+    /// The answer wasn't submitted, because the history shows it can't be right.
+    Refused(Refusal),
+    /// The answer can't be submitted as is, e.g. a picture of letters that still have to be read.
+    Unsubmittable(String),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Client(err) => write!(f, "{}", err),
+            Self::Refused(refusal) => write!(f, "not submitting: {}", refusal),
+            Self::Unsubmittable(reason) => write!(f, "can't submit: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(err: ClientError) -> Self {
+        Self::Client(err)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        Self::Client(err.into())
+    }
+}
+
+/// Every answer submitted for a year, read from `submissions/<year>.jsonl` with one `Attempt` per line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    attempts: Vec<Attempt>,
+}
+
+/// Path of the submission history for a year, within the crate's `submissions` directory.
+pub fn default_submissions_path(year: i32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("submissions")
+        .join(format!("{}.jsonl", year))
+}
+
+impl Submissions {
+    /// Load a submission history. A year without one yet has no attempts.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            attempts: read_jsonl(path)?,
+        })
+    }
+
+    /// Record an attempt, both here and at the end of the history file.
+    pub fn record(&mut self, path: &Path, attempt: Attempt) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let line = serde_json::to_string(&attempt).expect("Attempt is always serializable");
+        writeln!(file, "{}", line)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    pub fn attempts(&self, day: i32, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    /// Check whether an answer is worth submitting, given the earlier attempts at the same part.
+    pub fn check_candidate(&self, day: i32, part: u8, answer: &Answer) -> Result<(), Refusal> {
+        let candidate = answer.to_string();
+        let value = answer.as_integer();
+        for attempt in self.attempts(day, part) {
+            match &attempt.verdict {
+                Verdict::Correct => return Err(Refusal::AlreadyCorrect(attempt.answer.clone())),
+                verdict if verdict.is_wrong() && attempt.answer == candidate => {
+                    return Err(Refusal::KnownWrong(verdict.clone()))
+                }
+                _ => {}
+            }
+        }
+        if let Some(value) = value {
+            let bound = |verdict: Verdict| {
+                self.attempts(day, part)
+                    .filter(move |attempt| attempt.verdict == verdict)
+                    .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+            };
+            if let Some(too_high) = bound(Verdict::TooHigh).min().filter(|&high| value >= high) {
+                return Err(Refusal::AtOrAboveTooHigh(too_high));
+            }
+            if let Some(too_low) = bound(Verdict::TooLow).max().filter(|&low| value <= low) {
+                return Err(Refusal::AtOrBelowTooLow(too_low));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submit an answer unless the history shows it can't be right, then record the server's verdict.
+pub fn submit(
+    client: &mut Client,
+    submissions: &mut Submissions,
+    history_path: &Path,
+    year: i32,
+    day: i32,
+    part: u8,
+    answer: &Answer,
+) -> Result<Verdict, SubmitError> {
+    if answer.is_multiline() {
+        return Err(SubmitError::Unsubmittable(
+            "the answer is a picture, read it and pass it with --answer".to_string(),
+        ));
+    }
+    submissions
+        .check_candidate(day, part, answer)
+        .map_err(SubmitError::Refused)?;
+    let answer = answer.to_string();
+    let page = client.submit_answer(year, day, part, &answer)?;
+    let verdict = Verdict::parse(&page);
+    let submitted_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs());
+    submissions.record(
+        history_path,
+        Attempt {
+            day,
+            part,
+            answer,
+            verdict: verdict.clone(),
+            submitted_at,
+        },
+    )?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{mock_server, test_client};
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, ...</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 42s left to wait.</p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer!  You are one gold star closer \
+        to saving your vacation.</p></article>";
+
+    #[test]
+    fn check_parse_verdict() {
+        assert_eq!(Verdict::parse(TOO_HIGH), Verdict::TooHigh);
+        assert_eq!(
+            Verdict::parse(TOO_RECENT),
+            Verdict::RateLimited {
+                wait: Some("42s".to_string())
+            }
+        );
+        assert_eq!(Verdict::parse(CORRECT), Verdict::Correct);
+        assert_eq!(
+            Verdict::parse("That's not the right answer.  If you're stuck"),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::WrongLevel
+        );
+        assert_eq!(Verdict::parse("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn check_submit_against_mock_server() {
+        let (base_url, received) =
+            mock_server(vec![(200, TOO_HIGH), (200, TOO_RECENT), (200, CORRECT)]);
+        let mut client = test_client(&base_url);
+        let path =
+            std::env::temp_dir().join(format!("aoc_submissions_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut submissions = Submissions::load(&path).unwrap();
+        let mut submit = |answer: i64| {
+            submit(
+                &mut client,
+                &mut submissions,
+                &path,
+                2022,
+                7,
+                2,
+                &answer.into(),
+            )
+        };
+
+        assert_eq!(submit(500).unwrap(), Verdict::TooHigh);
+        // Neither the same answer nor a bigger one is sent again.
+        assert!(matches!(
+            submit(500),
+            Err(SubmitError::Refused(Refusal::KnownWrong(Verdict::TooHigh)))
+        ));
+        assert!(matches!(
+            submit(600),
+            Err(SubmitError::Refused(Refusal::AtOrAboveTooHigh(500)))
+        ));
+        assert!(matches!(submit(400).unwrap(), Verdict::RateLimited { .. }));
+        // Being rate limited doesn't make an answer wrong.
+        assert_eq!(submit(400).unwrap(), Verdict::Correct);
+        assert!(matches!(
+            submit(300),
+            Err(SubmitError::Refused(Refusal::AlreadyCorrect(_)))
+        ));
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 3);
+        assert_eq!(received[0].0, "POST");
+        assert_eq!(received[0].1, "/2022/day/7/answer");
+        assert_eq!(received[0].2, "session=abc123");
+        assert_eq!(received[0].3, "level=2&answer=500");
+        let reloaded = Submissions::load(&path).unwrap();
+        assert_eq!(reloaded.attempts(7, 2).count(), 3);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+use std::{fmt, time::Duration};
+
+use crate::{output::format_table, Answer};
+
+/// How a day's run went, as shown in the end-of-run summary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    Solved,
+    /// The day is still the untouched template.
+    Template,
+    /// The day's input file is empty, so it wasn't run.
+    EmptyInput,
+    Panicked,
+    /// The day returned an error, or its input couldn't be loaded.
+    Failed,
+    /// The day ran, but `--check` found an answer that doesn't match the recorded one.
+    Mismatched,
+}
+
+impl DayStatus {
+    const ALL: [Self; 6] = [
+        Self::Solved,
+        Self::Mismatched,
+        Self::Template,
+        Self::EmptyInput,
+        Self::Panicked,
+        Self::Failed,
+    ];
+}
+
+impl fmt::Display for DayStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Solved => "solved",
+            Self::Template => "template",
+            Self::EmptyInput => "empty input",
+            Self::Panicked => "panicked",
+            Self::Failed => "failed",
+            Self::Mismatched => "mismatched",
+        };
+        f.write_str(status)
+    }
+}
+
+/// One day's row in the end-of-run summary.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SummaryRow {
+    pub day: i32,
+    pub status: DayStatus,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    /// Time taken to parse the input and solve both parts, if the day ran and times are shown.
+    pub time: Option<Duration>,
+}
+
+impl SummaryRow {
+    pub fn new(day: i32, status: DayStatus) -> Self {
+        Self {
+            day,
+            status,
+            part_one: None,
+            part_two: None,
+            time: None,
+        }
+    }
+}
+
+/// Longest answer shown in full in the summary table.
+const MAX_ANSWER_WIDTH: usize = 20;
+
+fn answer_cell(answer: &Option<Answer>) -> String {
+    match answer {
+        None => "-".to_string(),
+        Some(Answer::Grid(rows)) => format!("({} row grid)", rows.len()),
+        Some(answer) => {
+            let answer = answer.to_string();
+            if answer.chars().count() > MAX_ANSWER_WIDTH {
+                let start = answer
+                    .chars()
+                    .take(MAX_ANSWER_WIDTH - 3)
+                    .collect::<String>();
+                format!("{}...", start)
+            } else {
+                answer
+            }
+        }
+    }
+}
+
+/// Format the summary rows as a table, followed by a line totalling each status and the time taken.
+pub fn format_summary_table(rows: &[SummaryRow]) -> String {
+    let header = ["Day", "Status", "Part 1", "Part 2", "Time"].map(String::from);
+    let mut cells = vec![header];
+    for row in rows {
+        cells.push([
+            row.day.to_string(),
+            row.status.to_string(),
+            answer_cell(&row.part_one),
+            answer_cell(&row.part_two),
+            row.time
+                .map_or_else(|| "-".to_string(), |time| format!("{:.2?}", time)),
+        ]);
+    }
+    // Left-align the status, right-align everything else.
+    let mut table = format_table(&cells, 1);
+    let counts = DayStatus::ALL
+        .iter()
+        .filter_map(|status| {
+            let count = rows.iter().filter(|row| row.status == *status).count();
+            (count > 0).then(|| format!("{} {}", count, status))
+        })
+        .collect::<Vec<_>>();
+    table.push_str(&format!(
+        "Total: {} days: {}",
+        rows.len(),
+        counts.join(", ")
+    ));
+    let times = rows.iter().filter_map(|row| row.time).collect::<Vec<_>>();
+    if !times.is_empty() {
+        table.push_str(&format!(" in {:.2?}", times.into_iter().sum::<Duration>()));
+    }
+    table.push('\n');
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_format_summary_table() {
+        let rows = [
+            SummaryRow {
+                part_one: Some(Answer::from(24000)),
+                part_two: Some(Answer::grid(["#.", ".#"])),
+                time: Some(Duration::from_micros(1500)),
+                ..SummaryRow::new(1, DayStatus::Solved)
+            },
+            SummaryRow {
+                part_one: Some(Answer::from("a much longer answer than fits")),
+                time: Some(Duration::from_micros(500)),
+                ..SummaryRow::new(2, DayStatus::Mismatched)
+            },
+            SummaryRow::new(12, DayStatus::Panicked),
+            SummaryRow::new(14, DayStatus::Template),
+        ];
+        assert_eq!(
+            format_summary_table(&rows),
+            "Day  Status                    Part 1        Part 2      Time
+  1  solved                     24000  (2 row grid)    1.50ms
+  2  mismatched  a much longer ans...             -  500.00µs
+ 12  panicked                       -             -         -
+ 14  template                       -             -         -
+Total: 4 days: 1 solved, 1 mismatched, 1 template, 1 panicked in 2.00ms
+"
+        );
+    }
+}
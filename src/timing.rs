@@ -0,0 +1,129 @@
+use std::{fmt, time::Duration};
+
+use clap::ValueEnum;
+
+/// One part of a puzzle, for running it on its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    /// The part's number, 1 or 2.
+    pub fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+/// How to run a solution, e.g. for `--repeat` or `--part`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    /// The only part to run, or `None` for both.
+    pub part: Option<Part>,
+    /// Number of timed runs. With more than one, `SolveResult` reports statistics across them.
+    pub repeat: usize,
+    /// Number of untimed runs before the timed ones, to warm up caches and the branch predictor.
+    pub warmup: usize,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            part: None,
+            repeat: 1,
+            warmup: 0,
+        }
+    }
+}
+
+/// Summary of one stage's wall-clock times over repeated runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimingStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Sample standard deviation, zero for a single run.
+    pub stddev: Duration,
+}
+
+impl TimingStats {
+    /// Summarise a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no timing samples");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        let secs = samples
+            .iter()
+            .map(Duration::as_secs_f64)
+            .collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = if secs.len() > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (secs.len() - 1) as f64
+        } else {
+            0.0
+        };
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl fmt::Display for TimingStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "median {:.2?}, min {:.2?}, mean {:.2?} ± {:.2?}",
+            self.median, self.min, self.mean, self.stddev
+        )
+    }
+}
+
+/// Timing statistics for each stage of a solution over repeated runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RunStats {
+    pub runs: usize,
+    pub parse: TimingStats,
+    pub part_one: TimingStats,
+    pub part_two: TimingStats,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_timing_stats() {
+        let stats = TimingStats::from_samples(&[4, 1, 3, 2].map(Duration::from_micros));
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        // sqrt(5 / 3) μs
+        assert_eq!(stats.stddev.as_nanos(), 1291);
+    }
+
+    #[test]
+    fn check_timing_stats_single_sample() {
+        let stats = TimingStats::from_samples(&[Duration::from_nanos(700)]);
+        assert_eq!(stats.median, Duration::from_nanos(700));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(
+            stats.to_string(),
+            "median 700.00ns, min 700.00ns, mean 700.00ns ± 0.00ns"
+        );
+    }
+}
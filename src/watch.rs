@@ -0,0 +1,143 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{examples::examples_dir, input::InputSource, registry::input_path};
+
+/// How often watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Files whose changes should re-run a day: its module, its input and its examples.
+pub fn watched_paths(root: &Path, source: &InputSource, year: i32, day: i32) -> Vec<PathBuf> {
+    let mut paths = vec![root
+        .join("src")
+        .join(format!("year{}", year))
+        .join(format!("day{:02}.rs", day))];
+    match source {
+        InputSource::Dir(dir) => paths.push(dir.join(input_path(year, &day.to_string()))),
+        InputSource::File(path) => paths.push(path.clone()),
+        InputSource::Stdin => {}
+    }
+    paths.push(examples_dir().join(input_path(year, &day.to_string())));
+    paths
+}
+
+/// Modification times of the watched files (including the files directly inside a watched
+/// directory), so that a save can be noticed by comparing snapshots.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot(Vec<(PathBuf, Option<SystemTime>)>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Self {
+        let mut times = vec![];
+        for path in paths {
+            times.push((path.clone(), modified(path)));
+            if let Ok(entries) = fs::read_dir(path) {
+                let mut files = entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .collect::<Vec<_>>();
+                files.sort();
+                times.extend(files.into_iter().map(|file| {
+                    let time = modified(&file);
+                    (file, time)
+                }));
+            }
+        }
+        Self(times)
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// Block until one of the watched files has been created, removed or saved since `previous`.
+pub fn wait_for_change(paths: &[PathBuf], previous: &Snapshot) {
+    while Snapshot::take(paths) == *previous {
+        thread::sleep(POLL_INTERVAL);
+    }
+    // Editors often save in several steps, so let them finish before rebuilding.
+    thread::sleep(POLL_INTERVAL);
+}
+
+/// Filters selecting a day's unit tests and its example tests in `cargo test`. Both name the year,
+/// as the filters match anywhere in a test's name.
+pub fn test_filters(year: i32, day: i32) -> [String; 2] {
+    [
+        format!("year{}::day{:02}::", year, day),
+        format!("year{}::day{:02}_", year, day),
+    ]
+}
+
+/// Rebuild and run a day's tests, returning whether they passed.
+pub fn run_tests(root: &Path, year: i32, day: i32) -> io::Result<bool> {
+    let status = Command::new(env!("CARGO"))
+        .args(["test", "--quiet", "--lib", "--test", "examples", "--"])
+        .args(test_filters(year, day))
+        .current_dir(root)
+        .status()?;
+    Ok(status.success())
+}
+
+/// Rebuild and run a day's solution with `args`, returning whether it succeeded.
+pub fn run_solution(root: &Path, year: i32, day: i32, args: &[String]) -> io::Result<bool> {
+    let status = Command::new(env!("CARGO"))
+        .args(["run", "--quiet", "--"])
+        .arg(day.to_string())
+        .args(["--year", &year.to_string()])
+        .args(args)
+        .current_dir(root)
+        .status()?;
+    Ok(status.success())
+}
+
+/// Clear the terminal and move the cursor to the top left.
+pub fn clear_screen() {
+    print!("\x1b[2J\x1b[H");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_watched_paths() {
+        let root = Path::new("/crate");
+        let source = InputSource::Dir(PathBuf::from("/inputs"));
+        let paths = watched_paths(root, &source, 2023, 4);
+        assert_eq!(paths[0], PathBuf::from("/crate/src/year2023/day04.rs"));
+        assert_eq!(paths[1], PathBuf::from("/inputs/2023/4"));
+        assert!(paths[2].ends_with("examples/2023/4"));
+        assert_eq!(
+            test_filters(2022, 4),
+            [
+                "year2022::day04::".to_string(),
+                "year2022::day04_".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn check_snapshot_sees_changes() {
+        let dir = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("1");
+        let paths = vec![dir.clone()];
+        let before = Snapshot::take(&paths);
+        fs::write(&file, "input").unwrap();
+        let created = Snapshot::take(&paths);
+        assert_ne!(before, created);
+        fs::File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+        assert_ne!(created, Snapshot::take(&paths));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -12,11 +12,13 @@
 // out to terminal following the labels "Part 1:" and "Part 2: respectively".
 // You can also run with `--bench` to benchmark the different parts of the solution.
 
-use crate::Solution;
+use crate::{Answer, Solution};
 
 #[derive(Clone, Debug)]
 pub struct Day00;
 
+crate::register_day!(Day00, 2022, 0, "Example");
+
 impl Solution for Day00 {
     type ParsedInput = String;
 
@@ -24,15 +26,16 @@ impl Solution for Day00 {
         input_lines.to_string()
     }
 
-    fn part_one(input: &mut Self::ParsedInput) -> String {
-        format!("{}", input.lines().map(sum_numbers_in_line).sum::<i32>())
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
+        input.lines().map(sum_numbers_in_line).sum::<i32>().into()
     }
 
-    fn part_two(input: &mut Self::ParsedInput) -> String {
-        format!(
-            "{}",
-            input.lines().map(square_difference_in_line).sum::<i32>()
-        )
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
+        input
+            .lines()
+            .map(square_difference_in_line)
+            .sum::<i32>()
+            .into()
     }
 }
 
@@ -69,7 +72,7 @@ mod tests {
                 "1, 2
 4, 3"
             ),
-            "10".to_string()
+            Answer::from(10)
         )
     }
 
@@ -80,7 +83,7 @@ mod tests {
                 "1, 2
 4, 3"
             ),
-            "2".to_string()
+            Answer::from(2)
         )
     }
 
@@ -92,7 +95,7 @@ mod tests {
 40, 30",
                 false
             ),
-            ("73".to_string(), "101".to_string())
+            (Answer::from(73), Answer::from(101))
         )
     }
 }
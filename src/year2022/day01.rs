@@ -1,8 +1,10 @@
-use crate::Solution;
+use crate::{Answer, Solution};
 
 #[derive(Clone, Debug)]
 pub struct Day01;
 
+crate::register_day!(Day01, 2022, 1, "Calorie Counting");
+
 impl Solution for Day01 {
     type ParsedInput = Vec<Vec<u32>>;
 
@@ -21,19 +23,22 @@ impl Solution for Day01 {
                 elf.push(line.parse::<u32>().unwrap())
             }
         }
+        if !elf.is_empty() {
+            input.push(elf);
+        }
         input
     }
 
-    fn part_one(input: &mut Self::ParsedInput) -> String {
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
         input
             .iter()
             .map(|elf| elf.iter().sum::<u32>())
             .max()
             .unwrap()
-            .to_string()
+            .into()
     }
 
-    fn part_two(input: &mut Self::ParsedInput) -> String {
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
         let mut results = vec![0, 0, 0];
         let elves = input
             .iter()
@@ -41,33 +46,11 @@ impl Solution for Day01 {
             .collect::<Vec<u32>>();
         for elf in elves {
             if elf > results[0] {
-                println!("{} is bigger than {}", elf, results[0]);
                 results.remove(0);
                 results.push(elf);
                 results.sort();
-                println!("Results: {:?}", results);
             }
         }
-        results.iter().sum::<u32>().to_string()
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn check_day01_part1_case1() {
-        assert_eq!(Day01::solve_part_one(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day01_part2_case1() {
-        assert_eq!(Day01::solve_part_two(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day01_both_case1() {
-        assert_eq!(Day01::solve("", false), ("0".to_string(), "0".to_string()))
+        results.iter().sum::<u32>().into()
     }
 }
@@ -1,8 +1,10 @@
-use crate::Solution;
+use crate::{Answer, Solution};
 
 #[derive(Clone, Debug)]
 pub struct Day02;
 
+crate::register_day!(Day02, 2022, 2, "Rock Paper Scissors");
+
 impl Solution for Day02 {
     type ParsedInput = String;
 
@@ -13,12 +15,12 @@ impl Solution for Day02 {
         input_lines.to_string()
     }
 
-    fn part_one(input: &mut Self::ParsedInput) -> String {
-        input.lines().map(score_round).sum::<u32>().to_string()
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
+        input.lines().map(score_round).sum::<u32>().into()
     }
 
-    fn part_two(input: &mut Self::ParsedInput) -> String {
-        input.lines().map(score_by_outcome).sum::<u32>().to_string()
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
+        input.lines().map(score_by_outcome).sum::<u32>().into()
     }
 }
 
@@ -55,32 +57,3 @@ fn score_by_outcome(round: &str) -> u32 {
         _ => 0,
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn check_day02_part1_case1() {
-        let input = r"A Y
-B X
-C Z";
-        assert_eq!(Day02::solve_part_one(input), "15".to_string())
-    }
-
-    #[test]
-    fn check_day02_part2_case1() {
-        assert_eq!(Day02::solve_part_two(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day02_both_case1() {
-        let input = r"A Y
-B X
-C Z";
-        assert_eq!(
-            Day02::solve(input, false),
-            ("15".to_string(), "0".to_string())
-        )
-    }
-}
@@ -1,10 +1,12 @@
 use std::collections::HashSet;
 
-use crate::Solution;
+use crate::{Answer, Solution};
 
 #[derive(Clone, Debug)]
 pub struct Day03;
 
+crate::register_day!(Day03, 2022, 3, "Rucksack Reorganization");
+
 impl Solution for Day03 {
     type ParsedInput = Vec<(HashSet<u8>, HashSet<u8>)>;
 
@@ -20,20 +22,12 @@ impl Solution for Day03 {
         input
     }
 
-    fn part_one(input: &mut Self::ParsedInput) -> String {
-        input
-            .iter()
-            .map(find_matching_item)
-            .sum::<u64>()
-            .to_string()
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
+        input.iter().map(find_matching_item).sum::<u64>().into()
     }
 
-    fn part_two(input: &mut Self::ParsedInput) -> String {
-        input
-            .chunks(3)
-            .map(find_group_item)
-            .sum::<u64>()
-            .to_string()
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
+        input.chunks(3).map(find_group_item).sum::<u64>().into()
     }
 }
 
@@ -66,23 +60,3 @@ fn find_group_item(rucksacks: &[(HashSet<u8>, HashSet<u8>)]) -> u64 {
     set_three.extend(rucksacks[2].1.iter());
     *intersected_set.intersection(&set_three).next().unwrap() as u64
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn check_day03_part1_case1() {
-        assert_eq!(Day03::solve_part_one(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day03_part2_case1() {
-        assert_eq!(Day03::solve_part_two(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day03_both_case1() {
-        assert_eq!(Day03::solve("", false), ("0".to_string(), "0".to_string()))
-    }
-}
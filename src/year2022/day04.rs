@@ -1,10 +1,15 @@
 use regex::Regex;
 
-use crate::Solution;
+use crate::{
+    error::{AocError, AocResult},
+    Answer, Solution,
+};
 
 #[derive(Clone, Debug)]
 pub struct Day04;
 
+crate::register_day!(Day04, 2022, 4, "Camp Cleanup");
+
 pub struct Elf {
     upper: u32,
     lower: u32,
@@ -32,16 +37,25 @@ impl Solution for Day04 {
     type ParsedInput = Vec<Pair>;
 
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
+        Self::try_parse_input(input_lines).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+        let re = Regex::new(r"(\d+)-(\d+),(\d+)-(\d+)").unwrap();
         let mut input = vec![];
-        for line in input_lines.lines() {
-            let re = Regex::new(r"(\d+)-(\d+),(\d+)-(\d+)").unwrap();
+        for (idx, line) in input_lines.lines().enumerate() {
             let caps = re
                 .captures(line)
-                .unwrap()
+                .ok_or_else(|| AocError::parse(idx, line, "expected two ranges like 2-4,6-8"))?
                 .iter()
                 .skip(1)
-                .map(|hit| hit.unwrap().as_str().parse::<u32>().unwrap())
-                .collect::<Vec<_>>();
+                .map(|hit| {
+                    hit.unwrap()
+                        .as_str()
+                        .parse::<u32>()
+                        .map_err(|err| AocError::parse(idx, line, err))
+                })
+                .collect::<AocResult<Vec<_>>>()?;
             let elf_a = Elf {
                 lower: caps[0],
                 upper: caps[1],
@@ -53,27 +67,27 @@ impl Solution for Day04 {
             let pair = Pair { a: elf_a, b: elf_b };
             input.push(pair);
         }
-        input
+        Ok(input)
     }
 
-    fn part_one(input: &mut Self::ParsedInput) -> String {
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
         let mut count = 0;
         for pair in input {
             if pair.range_contains_range() {
                 count += 1;
             }
         }
-        count.to_string()
+        count.into()
     }
 
-    fn part_two(input: &mut Self::ParsedInput) -> String {
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
         let mut count = 0;
         for pair in input {
             if pair.ranges_overlap() {
                 count += 1;
             }
         }
-        count.to_string()
+        count.into()
     }
 }
 
@@ -82,26 +96,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn check_day04_part1_case1() {
-        assert_eq!(Day04::solve_part_one(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day04_part2_case1() {
-        assert_eq!(Day04::solve_part_two(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day04_both_case1() {
-        let input = r"2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8";
+    fn check_day04_parse_error() {
         assert_eq!(
-            Day04::solve(input, false),
-            ("2".to_string(), "4".to_string())
+            Day04::try_parse_input("2-4,6-8\n2-3;4-5").err(),
+            Some(AocError::parse(
+                1,
+                "2-3;4-5",
+                "expected two ranges like 2-4,6-8"
+            ))
         )
     }
 }
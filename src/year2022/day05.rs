@@ -1,10 +1,15 @@
 use std::collections::HashMap;
 
-use crate::Solution;
+use crate::{
+    error::{AocError, AocResult},
+    Answer, Solution,
+};
 
 #[derive(Clone, Debug)]
 pub struct Day05;
 
+crate::register_day!(Day05, 2022, 5, "Supply Stacks");
+
 struct CrateMove {
     quantity: usize,
     orig: usize,
@@ -87,75 +92,93 @@ impl Solution for Day05 {
     type ParsedInput = Puzzle;
 
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
+        Self::try_parse_input(input_lines).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
         let reg = regex::Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
-        let input_lines = input_lines.lines();
-        let stacks_input: Vec<&str> = input_lines.clone().take(8).collect();
+        // The drawing of the stacks ends with a row of stack numbers, then a blank line.
+        let lines = input_lines.lines().collect::<Vec<_>>();
+        let numbers_idx = lines
+            .iter()
+            .position(|line| line.trim_start().starts_with('1'))
+            .unwrap_or(lines.len());
         let mut stacks = HashMap::new();
         for i in 1..10 {
             stacks.insert(i, vec![]);
         }
-        for layer in stacks_input.iter().rev() {
+        for layer in lines[..numbers_idx].iter().rev() {
             let layer = layer.chars().collect::<Vec<_>>();
             for i in 0..9 {
                 let idx = 4 * i + 1;
-                let value = layer[idx];
-                if !value.is_whitespace() {
-                    let stack = stacks.get_mut(&(i + 1)).unwrap();
-                    stack.push(value)
+                // Editors often strip trailing whitespace, so a short layer just has empty stacks at the end.
+                if let Some(&value) = layer.get(idx) {
+                    if !value.is_whitespace() {
+                        let stack = stacks.get_mut(&(i + 1)).unwrap();
+                        stack.push(value)
+                    }
                 }
             }
         }
-        let instructions = input_lines
-            .skip(10)
-            .map(|line| parse_crate_move(line, &reg))
-            .collect::<Vec<_>>();
+        let instructions = lines
+            .iter()
+            .enumerate()
+            .skip(numbers_idx + 2)
+            .map(|(idx, line)| parse_crate_move(idx, line, &reg))
+            .collect::<AocResult<Vec<_>>>()?;
         let stacks = CrateStacks { stacks };
-        Puzzle {
+        Ok(Puzzle {
             instructions,
             stacks,
-        }
+        })
     }
 
-    fn part_one(input: &mut Self::ParsedInput) -> String {
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
         input.execute_instructions();
-        input.top_crates()
+        input.top_crates().into()
     }
 
-    fn part_two(input: &mut Self::ParsedInput) -> String {
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
         input.execute_instructions_multi();
-        input.top_crates()
+        input.top_crates().into()
     }
 }
 
-fn parse_crate_move(instruction: &str, reg: &regex::Regex) -> CrateMove {
-    let caps = reg.captures(instruction).unwrap();
-    let mut caps = caps.iter();
-    let quantity = caps
-        .nth(1)
-        .unwrap()
-        .unwrap()
-        .as_str()
-        .parse::<usize>()
-        .unwrap();
-    let from = caps
-        .next()
-        .unwrap()
-        .unwrap()
-        .as_str()
-        .parse::<usize>()
-        .unwrap();
-    let to = caps
-        .next()
-        .unwrap()
-        .unwrap()
-        .as_str()
-        .parse::<usize>()
-        .unwrap();
-    CrateMove {
-        quantity: quantity,
+fn parse_crate_move(
+    line_idx: usize,
+    instruction: &str,
+    reg: &regex::Regex,
+) -> AocResult<CrateMove> {
+    let caps = reg.captures(instruction).ok_or_else(|| {
+        AocError::parse(
+            line_idx,
+            instruction,
+            "expected `move <n> from <stack> to <stack>`",
+        )
+    })?;
+    let field = |i: usize| {
+        caps[i]
+            .parse::<usize>()
+            .map_err(|err| AocError::parse(line_idx, instruction, err))
+    };
+    let quantity = field(1)?;
+    let from = field(2)?;
+    let to = field(3)?;
+    if let Some(stack) = [from, to]
+        .into_iter()
+        .find(|stack| !(1..10).contains(stack))
+    {
+        return Err(AocError::parse(
+            line_idx,
+            instruction,
+            format!("there is no stack {}", stack),
+        ));
+    }
+    Ok(CrateMove {
+        quantity,
         orig: from,
         dest: to,
-    }
+    })
 }
 
 #[cfg(test)]
@@ -163,36 +186,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn check_day05_part1_case1() {
-        assert_eq!(Day05::solve_part_one(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day05_part2_case1() {
-        assert_eq!(Day05::solve_part_two(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day05_both_case1() {
+    fn check_day05_parse_error() {
         assert_eq!(
-            Day05::solve(
-                r"                    
-                                        
-                                        
-                                                        
-                                                        
-                    [D]    
-                    [N] [C]    
-                    [Z] [M] [P]
-                    1   2   3 
-                    
-                    move 1 from 2 to 1
-                    move 3 from 1 to 3
-                    move 2 from 2 to 1
-                    move 1 from 1 to 2",
-                false
-            ),
-            ("CMZ".to_string(), "0".to_string())
+            Day05::try_parse_input("[A]\n 1 \n\nmove 1 from 2 to 10").err(),
+            Some(AocError::parse(
+                3,
+                "move 1 from 2 to 10",
+                "there is no stack 10"
+            ))
         )
     }
 }
@@ -1,10 +1,12 @@
 use std::collections::HashSet;
 
-use crate::Solution;
+use crate::{Answer, Solution};
 
 #[derive(Clone, Debug)]
 pub struct Day06;
 
+crate::register_day!(Day06, 2022, 6, "Tuning Trouble");
+
 impl Solution for Day06 {
     type ParsedInput = String;
 
@@ -15,16 +17,16 @@ impl Solution for Day06 {
         input_lines.to_string()
     }
 
-    fn part_one(input: &mut Self::ParsedInput) -> String {
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
         find_unique(4, input)
     }
 
-    fn part_two(input: &mut Self::ParsedInput) -> String {
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
         find_unique(14, input)
     }
 }
 
-fn find_unique(num: usize, input: &mut String) -> String {
+fn find_unique(num: usize, input: &mut String) -> Answer {
     let mut set = HashSet::new();
     (num + input
         .as_bytes()
@@ -41,25 +43,5 @@ fn find_unique(num: usize, input: &mut String) -> String {
             !dupe
         })
         .unwrap())
-    .to_string()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn check_day06_part1_case1() {
-        assert_eq!(Day06::solve_part_one(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day06_part2_case1() {
-        assert_eq!(Day06::solve_part_two(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day06_both_case1() {
-        assert_eq!(Day06::solve("", false), ("0".to_string(), "0".to_string()))
-    }
+    .into()
 }
@@ -1,10 +1,12 @@
 use petgraph::{prelude::Graph, visit::EdgeRef, Directed, Direction::Outgoing};
 
-use crate::Solution;
+use crate::{Answer, Solution};
 
 #[derive(Clone, Debug)]
 pub struct Day07;
 
+crate::register_day!(Day07, 2022, 7, "No Space Left On Device");
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum EdgeType {
     Parent,
@@ -128,10 +130,6 @@ impl Solution for Day07 {
                 {
                     None
                 } else {
-                    println!(
-                        "Getting dir size for {}",
-                        filetree.node_weight(node).unwrap()
-                    );
                     let dir_size = directory_size(&filetree, node);
                     if node == root {
                         root_size = dir_size;
@@ -144,23 +142,23 @@ impl Solution for Day07 {
         (dir_sizes, root_size)
     }
 
-    fn part_one(input: &mut Self::ParsedInput) -> String {
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
         input
             .0
             .iter()
             .filter(|dir_size| **dir_size <= 100000)
             .sum::<u64>()
-            .to_string()
+            .into()
     }
 
-    fn part_two(input: &mut Self::ParsedInput) -> String {
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
         input
             .0
             .iter()
             .filter(|dir_size| **dir_size > input.1 - 40000000)
             .min()
             .unwrap()
-            .to_string()
+            .into()
     }
 }
 
@@ -213,50 +211,3 @@ fn directory_size(
 //     size: Option<usize>,
 //     parent: Option<&DirTree>,
 // }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn check_day07_part1_case1() {
-        assert_eq!(
-            Day07::solve_part_one(
-                "$ cd /
-        $ ls
-        dir a
-        14848514 b.txt
-        8504156 c.dat
-        dir d
-        $ cd a
-        $ ls
-        dir e
-        29116 f
-        2557 g
-        62596 h.lst
-        $ cd e
-        $ ls
-        584 i
-        $ cd ..
-        $ cd ..
-        $ cd d
-        $ ls
-        4060174 j
-        8033020 d.log
-        5626152 d.ext
-        7214296 k"
-            ),
-            "95437".to_string()
-        )
-    }
-
-    #[test]
-    fn check_day07_part2_case1() {
-        assert_eq!(Day07::solve_part_two(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day07_both_case1() {
-        assert_eq!(Day07::solve("", false), ("0".to_string(), "0".to_string()))
-    }
-}
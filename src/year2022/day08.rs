@@ -0,0 +1,59 @@
+use crate::{
+    grid::{Direction, Grid, Point},
+    Answer, Solution,
+};
+
+#[derive(Clone, Debug)]
+pub struct Day08;
+
+crate::register_day!(Day08, 2022, 8, "Treetop Tree House");
+
+impl Solution for Day08 {
+    type ParsedInput = Grid<u8>;
+
+    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+        Grid::parse(input_lines, |digit| digit.to_digit(10).unwrap() as u8)
+    }
+
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
+        // A tree is visible if every tree between it and some edge is shorter.
+        input
+            .points()
+            .filter(|&tree| {
+                Direction::ORTHOGONAL.into_iter().any(|direction| {
+                    input
+                        .ray(tree, direction)
+                        .all(|other| input[other] < input[tree])
+                })
+            })
+            .count()
+            .into()
+    }
+
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
+        input
+            .points()
+            .map(|tree| scenic_score(input, tree))
+            .max()
+            .unwrap()
+            .into()
+    }
+}
+
+/// The product of how many trees can be seen from `tree` in each direction, where the view stops
+/// at the edge or at the first tree at least as tall.
+fn scenic_score(forest: &Grid<u8>, tree: Point) -> usize {
+    Direction::ORTHOGONAL
+        .into_iter()
+        .map(|direction| {
+            let mut score = 0;
+            for other in forest.ray(tree, direction) {
+                score += 1;
+                if forest[other] >= forest[tree] {
+                    break;
+                }
+            }
+            score
+        })
+        .product()
+}
@@ -2,11 +2,16 @@ use std::{cmp::max, collections::HashSet};
 
 use nalgebra::{vector, Vector2};
 
-use crate::Solution;
+use crate::{
+    error::{AocError, AocResult},
+    Answer, Solution,
+};
 
 #[derive(Clone, Debug)]
 pub struct Day09;
 
+crate::register_day!(Day09, 2022, 9, "Rope Bridge");
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MoveInstruction {
     Up,
@@ -40,22 +45,32 @@ impl Solution for Day09 {
     type ParsedInput = Vec<MoveInstruction>;
 
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
-        // You can leave this as-is if you want to handle the input differently for each part.
-        // Alternatively, you can parse the input into two completely separate structs and pass
-        // them through together in a tuple.
+        Self::try_parse_input(input_lines).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
         let mut instruction_list = Vec::new();
-        for line in input_lines.lines() {
+        for (idx, line) in input_lines.lines().enumerate() {
             let mut split = line.split_whitespace();
-            let instruction = MoveInstruction::from_str(split.next().unwrap()).unwrap();
-            let distance = split.next().unwrap().parse::<i32>().unwrap();
+            let instruction = split
+                .next()
+                .and_then(MoveInstruction::from_str)
+                .ok_or_else(|| {
+                    AocError::parse(idx, line, "expected a direction of U, D, L or R")
+                })?;
+            let distance = split
+                .next()
+                .ok_or_else(|| AocError::parse(idx, line, "missing distance"))?
+                .parse::<i32>()
+                .map_err(|err| AocError::parse(idx, line, err))?;
             for _i in 0..distance {
                 instruction_list.push(instruction.clone());
             }
         }
-        instruction_list
+        Ok(instruction_list)
     }
 
-    fn part_one(input: &mut Self::ParsedInput) -> String {
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
         let mut visited_locations: HashSet<Vector2<i32>> = HashSet::new();
         let mut knots: Vec<Vector2<i32>> = vec![vector![0, 0]; 2];
         visited_locations.insert(knots[1].clone());
@@ -70,10 +85,10 @@ impl Solution for Day09 {
             visited_locations.insert(knots[1].clone());
         }
 
-        visited_locations.len().to_string()
+        visited_locations.len().into()
     }
 
-    fn part_two(input: &mut Self::ParsedInput) -> String {
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
         let mut visited_locations: HashSet<Vector2<i32>> = HashSet::new();
         let mut knots: Vec<Vector2<i32>> = vec![vector![0, 0]; 10];
         visited_locations.insert(knots[9].clone());
@@ -88,7 +103,7 @@ impl Solution for Day09 {
             visited_locations.insert(knots[9].clone());
         }
 
-        visited_locations.len().to_string()
+        visited_locations.len().into()
     }
 }
 
@@ -109,29 +124,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn check_day09_part1_case1() {
+    fn check_day09_parse_error() {
         assert_eq!(
-            Day09::solve_part_one(
-                r"R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2"
-            ),
-            "13".to_string()
+            Day09::try_parse_input("R 4\nX 2").err(),
+            Some(AocError::parse(
+                1,
+                "X 2",
+                "expected a direction of U, D, L or R"
+            ))
         )
     }
-
-    #[test]
-    fn check_day09_part2_case1() {
-        assert_eq!(Day09::solve_part_two(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day09_both_case1() {
-        assert_eq!(Day09::solve("", false), ("0".to_string(), "0".to_string()))
-    }
 }
@@ -1,10 +1,12 @@
 use std::{cell::RefCell, collections::VecDeque, rc::Rc};
 
-use crate::Solution;
+use crate::{Answer, Solution};
 
 #[derive(Clone, Debug)]
 pub struct Day11;
 
+crate::register_day!(Day11, 2022, 11, "Monkey in the Middle");
+
 pub struct Monkey {
     items: VecDeque<u64>,
     operation: Box<dyn Fn(u64) -> u64>,
@@ -44,7 +46,7 @@ impl Solution for Day11 {
         0.to_string()
     }
 
-    fn part_one(_input: &mut Self::ParsedInput) -> String {
+    fn part_one(_input: &mut Self::ParsedInput) -> Answer {
         let mut monkeys = create_monkeys();
         for _round in 0..20 {
             for monkey in monkeys.iter_mut() {
@@ -57,10 +59,10 @@ impl Solution for Day11 {
             .collect::<Vec<u32>>();
         inspections.sort();
 
-        (inspections[inspections.len() - 1] * inspections[inspections.len() - 2]).to_string()
+        (inspections[inspections.len() - 1] * inspections[inspections.len() - 2]).into()
     }
 
-    fn part_two(_input: &mut Self::ParsedInput) -> String {
+    fn part_two(_input: &mut Self::ParsedInput) -> Answer {
         let mut monkeys = create_monkeys();
         for _round in 0..10000 {
             for monkey in monkeys.iter_mut() {
@@ -80,7 +82,7 @@ impl Solution for Day11 {
         //     inspections[inspections.len() - 2]
         // );
         ((inspections[inspections.len() - 1] as u64) * (inspections[inspections.len() - 2] as u64))
-            .to_string()
+            .into()
     }
 }
 
@@ -210,23 +212,3 @@ fn create_monkeys() -> Vec<Rc<RefCell<Monkey>>> {
     (*monkeys[7]).borrow_mut().set_false_mk(monkeys[5].clone());
     monkeys
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn check_day11_part1_case1() {
-        assert_eq!(Day11::solve_part_one(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day11_part2_case1() {
-        assert_eq!(Day11::solve_part_two(""), "0".to_string())
-    }
-
-    #[test]
-    fn check_day11_both_case1() {
-        assert_eq!(Day11::solve("", false), ("0".to_string(), "0".to_string()))
-    }
-}
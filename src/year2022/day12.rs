@@ -0,0 +1,106 @@
+use crate::{
+    error::{AocError, AocResult},
+    grid::{Grid, Point},
+    search::{bfs, bfs_multi},
+    Answer, Solution,
+};
+
+#[derive(Clone, Debug)]
+pub struct Day12;
+
+crate::register_day!(Day12, 2022, 12, "Hill Climbing Algorithm");
+
+pub struct WorldMap {
+    heights: Grid<u8>,
+    start: Point,
+    end: Point,
+}
+
+impl WorldMap {
+    /// The squares that can be climbed to from `from`, which are at most one higher.
+    fn climbable(&self, from: Point) -> impl Iterator<Item = Point> + '_ {
+        self.heights
+            .neighbours4(from)
+            .filter(move |&to| self.heights[to] <= self.heights[from] + 1)
+    }
+}
+
+impl Solution for Day12 {
+    type ParsedInput = WorldMap;
+
+    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+        Self::try_parse_input(input_lines).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+        let map = Grid::try_parse(input_lines, |square| match square {
+            'a'..='z' | 'S' | 'E' => Ok(square),
+            _ => Err(format!("invalid square {:?}", square)),
+        })?;
+        let find_marker = |marker: char, name: &str| {
+            map.find(&marker)
+                .ok_or_else(|| AocError::input(format!("no {} square {:?}", name, marker)))
+        };
+        let start = find_marker('S', "start")?;
+        let end = find_marker('E', "end")?;
+        let heights = map.map(|&square| match square {
+            'S' => b'a',
+            'E' => b'z',
+            height => u8::try_from(height).unwrap(),
+        });
+        Ok(WorldMap {
+            heights,
+            start,
+            end,
+        })
+    }
+
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
+        bfs(
+            input.start,
+            |&square| input.climbable(square),
+            |&square| square == input.end,
+        )
+        .goal_distance()
+        .unwrap()
+        .into()
+    }
+
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
+        // Searching from every lowest square at once finds the nearest one to the end.
+        let lowest = input
+            .heights
+            .iter()
+            .filter(|(_, &height)| height == b'a')
+            .map(|(square, _)| square);
+        bfs_multi(
+            lowest,
+            |&square| input.climbable(square),
+            |&square| square == input.end,
+        )
+        .goal_distance()
+        .unwrap()
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_day12_parse_error() {
+        assert_eq!(
+            Day12::try_parse_input("Sab\nc1E").err(),
+            Some(AocError::parse(1, "c1E", "invalid square '1'"))
+        );
+        assert_eq!(
+            Day12::try_parse_input("aab\ncdE").err(),
+            Some(AocError::input("no start square 'S'"))
+        );
+        assert_eq!(
+            Day12::try_parse_input("Sab\ncde").err(),
+            Some(AocError::input("no end square 'E'"))
+        );
+    }
+}
@@ -1,10 +1,15 @@
 use std::collections::HashMap;
 
-use crate::Solution;
+use crate::{
+    error::{AocError, AocResult},
+    Answer, Solution,
+};
 
 #[derive(Clone, Debug)]
 pub struct Day13;
 
+crate::register_day!(Day13, 2022, 13, "Distress Signal");
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Packet {
     List(Vec<Packet>),
@@ -12,14 +17,28 @@ pub enum Packet {
 }
 
 fn parse_packet(input: &str) -> Packet {
+    try_parse_packet(input).unwrap_or_else(|reason| panic!("{}", reason))
+}
+
+fn try_parse_packet(input: &str) -> Result<Packet, String> {
     let mut input = input.chars().peekable();
     let mut current = Packet::List(Vec::new());
     let mut stack = Vec::new();
     let mut value = None;
+    // Whether the outermost list has been closed, after which the line should end.
+    let mut closed = false;
 
-    input.next(); // skip the first [
+    if input.next() != Some('[') {
+        return Err("packet should start with [".to_string());
+    }
 
     while let Some(c) = input.next() {
+        if closed {
+            return Err(match c {
+                ']' => "unmatched ] in packet".to_string(),
+                _ => format!("unexpected {:?} after the end of the packet", c),
+            });
+        }
         if let Some(inner_value) = value {
             match current {
                 Packet::List(ref mut list) => {
@@ -48,6 +67,8 @@ fn parse_packet(input: &str) -> Packet {
                         }
                     }
                     current = parent;
+                } else {
+                    closed = true;
                 }
             }
             '0'..='9' => {
@@ -59,25 +80,36 @@ fn parse_packet(input: &str) -> Packet {
                 }
             }
             ',' => {}
-            _ => panic!("Invalid character in packet"),
+            _ => return Err(format!("invalid character {:?} in packet", c)),
         }
     }
-    current
+    if !closed {
+        return Err("unmatched [ in packet".to_string());
+    }
+    Ok(current)
 }
 
 impl Solution for Day13 {
     type ParsedInput = (HashMap<usize, (Packet, Packet)>, Vec<Packet>);
 
     fn parse_input(input_lines: &str) -> Self::ParsedInput {
-        // You can leave this as-is if you want to handle the input differently for each part.
-        // Alternatively, you can parse the input into two completely separate structs and pass
-        // them through together in a tuple.
+        Self::try_parse_input(input_lines).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
         let mut data1: HashMap<usize, (Packet, Packet)> = HashMap::new();
         let mut data2: Vec<Packet> = Vec::new();
         for (idx, packet_pair) in input_lines.split("\n\n").enumerate() {
-            let mut packet_pair = packet_pair.split("\n");
-            let packet_1 = parse_packet(packet_pair.next().unwrap());
-            let packet_2 = parse_packet(packet_pair.next().unwrap());
+            // Each pair takes up two lines plus the blank separator line.
+            let line_idx = 3 * idx;
+            let mut packet_pair = packet_pair.split('\n');
+            let mut next_packet = |offset: usize| {
+                let line = packet_pair.next().unwrap_or_default();
+                try_parse_packet(line)
+                    .map_err(|reason| AocError::parse(line_idx + offset, line, reason))
+            };
+            let packet_1 = next_packet(0)?;
+            let packet_2 = next_packet(1)?;
             data1.insert(idx + 1, (packet_1.clone(), packet_2.clone()));
             data2.push(packet_1);
             data2.push(packet_2);
@@ -85,10 +117,10 @@ impl Solution for Day13 {
         data2.push(parse_packet("[[2]]"));
         data2.push(parse_packet("[[6]]"));
 
-        (data1, data2)
+        Ok((data1, data2))
     }
 
-    fn part_one(input: &mut Self::ParsedInput) -> String {
+    fn part_one(input: &mut Self::ParsedInput) -> Answer {
         let mut correct_idxs = vec![];
         for (k, (packet_1, packet_2)) in input.0.iter() {
             // println!("Comparing:");
@@ -100,10 +132,10 @@ impl Solution for Day13 {
                 correct_idxs.push(*k);
             }
         }
-        correct_idxs.iter().sum::<usize>().to_string()
+        correct_idxs.iter().sum::<usize>().into()
     }
 
-    fn part_two(input: &mut Self::ParsedInput) -> String {
+    fn part_two(input: &mut Self::ParsedInput) -> Answer {
         let mut packets = input.1.clone();
         let first_marker = parse_packet("[[2]]");
         let second_marker = parse_packet("[[6]]");
@@ -119,7 +151,7 @@ impl Solution for Day13 {
             }
         }
 
-        (first * second).to_string()
+        (first * second).into()
     }
 }
 
@@ -205,48 +237,6 @@ impl Ord for Packet {
 mod tests {
     use super::*;
 
-    #[test]
-    fn check_day13_part1_case1() {
-        assert_eq!(
-            Day13::solve_part_one(
-                r"[1,1,3,1,1]
-[1,1,5,1,1]
-
-[[1],[2,3,4]]
-[[1],4]
-
-[9]
-[[8,7,6]]
-
-[[4,4],4,4]
-[[4,4],4,4,4]
-
-[7,7,7,7]
-[7,7,7]
-
-[]
-[3]
-
-[[[]]]
-[[]]
-
-[1,[2,[3,[4,[5,6,7]]]],8,9]
-[1,[2,[3,[4,[5,6,0]]]],8,9]"
-            ),
-            "13".to_string()
-        )
-    }
-
-    // #[test]
-    // fn check_day13_part2_case1() {
-    //     assert_eq!(Day13::solve_part_two(""), "0".to_string())
-    // }
-
-    // #[test]
-    // fn check_day13_both_case1() {
-    //     assert_eq!(Day13::solve("", false), ("0".to_string(), "0".to_string()))
-    // }
-
     #[test]
     fn day13_parse_basic() {
         assert_eq!(
@@ -290,6 +280,26 @@ mod tests {
         assert_eq!(parse_packet("[1]"), Packet::List(vec![Packet::Value(1)]));
     }
 
+    #[test]
+    fn check_day13_parse_error() {
+        assert_eq!(
+            Day13::try_parse_input("[1]\n[2]\n\n[1,a]\n[3]").err(),
+            Some(AocError::parse(
+                3,
+                "[1,a]",
+                "invalid character 'a' in packet"
+            ))
+        );
+        assert_eq!(
+            Day13::try_parse_input("[1\n[2]").err(),
+            Some(AocError::parse(0, "[1", "unmatched [ in packet"))
+        );
+        assert_eq!(
+            Day13::try_parse_input("[]]\n[1]").err(),
+            Some(AocError::parse(0, "[]]", "unmatched ] in packet"))
+        );
+    }
+
     #[test]
     fn day13_compare_true1() {
         assert!(compare_packets(
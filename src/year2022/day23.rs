@@ -1,8 +1,10 @@
-use crate::Solution;
+use crate::{Answer, Solution};
 
 #[derive(Clone, Debug)]
 pub struct Day23;
 
+crate::register_day!(Day23, 2022, 23, "Unstable Diffusion", unimplemented);
+
 impl Solution for Day23 {
     type ParsedInput = String;
 
@@ -13,14 +15,14 @@ impl Solution for Day23 {
         input_lines.to_string()
     }
 
-    fn part_one(_input: &mut Self::ParsedInput) -> String {
+    fn part_one(_input: &mut Self::ParsedInput) -> Answer {
         // TODO: implement part one
-        0.to_string()
+        0.into()
     }
 
-    fn part_two(_input: &mut Self::ParsedInput) -> String {
+    fn part_two(_input: &mut Self::ParsedInput) -> Answer {
         // TODO: implement part two
-        0.to_string()
+        0.into()
     }
 }
 
@@ -30,16 +32,16 @@ mod tests {
 
     #[test]
     fn check_day23_part1_case1() {
-        assert_eq!(Day23::solve_part_one(""), "0".to_string())
+        assert_eq!(Day23::solve_part_one(""), Answer::from(0))
     }
 
     #[test]
     fn check_day23_part2_case1() {
-        assert_eq!(Day23::solve_part_two(""), "0".to_string())
+        assert_eq!(Day23::solve_part_two(""), Answer::from(0))
     }
 
     #[test]
     fn check_day23_both_case1() {
-        assert_eq!(Day23::solve("", false), ("0".to_string(), "0".to_string()))
+        assert_eq!(Day23::solve("", false), (Answer::from(0), Answer::from(0)))
     }
 }
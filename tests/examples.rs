@@ -0,0 +1,3 @@
+//! Tests for every puzzle example in `examples/`, generated by `build.rs`.
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));